@@ -0,0 +1,450 @@
+//! Compact, embeddable dependency-audit document for a [`HarvesterBatch`],
+//! in the spirit of the `cargo auditable` SBOM format: dependency edges are
+//! stored as indices into a single flat package list rather than repeating
+//! names, so the whole thing is cheap enough to embed in a built artifact.
+//!
+//! [`HarvesterBatch::to_audit_info`] builds a [`VersionInfo`] from a batch;
+//! [`HarvesterBatch::from_audit_info`] reconstructs a (necessarily lossier)
+//! [`HarvesterBatch`] from one, for tooling that only has the audit document
+//! to work from.
+
+use crate::model::{EdgeKind, HarvesterBatch, LocalPackageNode, LocalVcsRef};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// A minimal, self-contained dependency manifest: every package a batch
+/// resolved to, with dependency edges expressed as indices into `packages`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VersionInfo {
+    pub packages: Vec<AuditPackage>,
+}
+
+/// One entry in a [`VersionInfo`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditPackage {
+    pub name: String,
+    pub version: String,
+    pub source: AuditSource,
+    pub kind: AuditKind,
+
+    /// Indices into the owning [`VersionInfo::packages`], not names — this
+    /// is what keeps the document compact.
+    pub dependencies: Vec<usize>,
+}
+
+/// Where a package's sources came from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuditSource {
+    CratesIo,
+    Registry(String),
+    Git(String),
+    Local,
+}
+
+/// How a package is used, ordered least to most permissive so that
+/// `Ord`/`max` implements a "more permissive wins" merge rule: a package
+/// reachable from the root via more than one edge kind takes the most
+/// permissive one (runtime > build > development).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum AuditKind {
+    Development,
+    Build,
+    Runtime,
+}
+
+impl From<EdgeKind> for AuditKind {
+    fn from(kind: EdgeKind) -> Self {
+        match kind {
+            EdgeKind::Normal => AuditKind::Runtime,
+            EdgeKind::Build => AuditKind::Build,
+            EdgeKind::Dev => AuditKind::Development,
+        }
+    }
+}
+
+impl HarvesterBatch {
+    /// Converts this batch into a compact [`VersionInfo`] audit document.
+    ///
+    /// Packages are deduplicated by `(name, version)`; the first node is
+    /// treated as the root package (matching the `nodes.first()` convention
+    /// used elsewhere for the primary package) and is always placed first
+    /// and marked [`AuditKind::Runtime`]. The remaining packages are sorted
+    /// by `(name, version)` for deterministic index assignment.
+    ///
+    /// Each edge's [`AuditKind`] comes from the declaring package's
+    /// [`LocalPackageNode::dependency_kinds`] (defaulting to
+    /// [`EdgeKind::Normal`]/[`AuditKind::Runtime`] when absent); a package
+    /// reachable via more than one edge takes the most permissive kind
+    /// (runtime > build > development). Packages not reachable from the root
+    /// at all are conservatively classified [`AuditKind::Development`].
+    pub fn to_audit_info(&self) -> VersionInfo {
+        let mut unique: Vec<&LocalPackageNode> = Vec::new();
+        for node in &self.nodes {
+            if !unique
+                .iter()
+                .any(|n| n.name == node.name && n.version == node.version)
+            {
+                unique.push(node);
+            }
+        }
+
+        if unique.is_empty() {
+            return VersionInfo { packages: vec![] };
+        }
+
+        let root = unique[0];
+        let mut rest: Vec<&LocalPackageNode> = unique[1..].to_vec();
+        rest.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.version.cmp(&b.version)));
+
+        let mut ordered = Vec::with_capacity(unique.len());
+        ordered.push(root);
+        ordered.extend(rest);
+
+        // First occurrence by name wins: dependency strings carry a
+        // requirement (`"name req"`), not an exact version, so an edge to a
+        // name shared by several packages resolves to the first of them in
+        // this ordering (the root, when it shares a name with a dependency).
+        let mut index_by_name: HashMap<&str, usize> = HashMap::new();
+        for (index, pkg) in ordered.iter().enumerate() {
+            index_by_name.entry(pkg.name.as_str()).or_insert(index);
+        }
+
+        let edges: Vec<Vec<(usize, EdgeKind)>> = ordered
+            .iter()
+            .map(|pkg| {
+                pkg.dependencies
+                    .iter()
+                    .filter_map(|dep| dep.split_whitespace().next())
+                    .filter_map(|dep_name| {
+                        index_by_name.get(dep_name).copied().map(|target| {
+                            let kind = pkg
+                                .dependency_kinds
+                                .get(dep_name)
+                                .copied()
+                                .unwrap_or_default();
+                            (target, kind)
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let reachable = reachable_from_root(&edges);
+
+        // The most permissive `AuditKind` reached via any edge whose source
+        // is itself reachable (or is the root), so a package only ever
+        // reached through a build/dev edge elsewhere still comes out
+        // `Runtime` if some other reachable package also depends on it
+        // normally.
+        let mut best_kind: HashMap<usize, AuditKind> = HashMap::new();
+        for (source, targets) in edges.iter().enumerate() {
+            if source != 0 && !reachable.contains(&source) {
+                continue;
+            }
+            for &(target, kind) in targets {
+                let candidate = AuditKind::from(kind);
+                best_kind
+                    .entry(target)
+                    .and_modify(|existing| *existing = (*existing).max(candidate))
+                    .or_insert(candidate);
+            }
+        }
+
+        let packages = ordered
+            .iter()
+            .enumerate()
+            .map(|(index, pkg)| {
+                let kind = if index == 0 {
+                    AuditKind::Runtime
+                } else {
+                    best_kind
+                        .get(&index)
+                        .copied()
+                        .unwrap_or(AuditKind::Development)
+                };
+                AuditPackage {
+                    name: pkg.name.clone(),
+                    version: pkg.version.clone(),
+                    source: package_source(index, pkg, &self.source_vcs),
+                    kind,
+                    dependencies: edges[index].iter().map(|&(target, _)| target).collect(),
+                }
+            })
+            .collect();
+
+        VersionInfo { packages }
+    }
+
+    /// Reconstructs a [`HarvesterBatch`] from a [`VersionInfo`].
+    ///
+    /// This is lossy in the other direction: [`AuditPackage`] carries no
+    /// description, license, or ecosystem, so those come back empty (the
+    /// audit format mirrors `cargo auditable`, so `ecosystem` is always
+    /// `"cargo"`), and the root's VCS ref is recovered only when its source
+    /// is [`AuditSource::Git`], and then as a URL without a commit.
+    pub fn from_audit_info(info: &VersionInfo) -> HarvesterBatch {
+        let nodes = info
+            .packages
+            .iter()
+            .map(|pkg| LocalPackageNode {
+                name: pkg.name.clone(),
+                version: pkg.version.clone(),
+                ecosystem: "cargo".to_string(),
+                description: None,
+                license: None,
+                dependencies: pkg
+                    .dependencies
+                    .iter()
+                    .filter_map(|&index| info.packages.get(index))
+                    .map(|dep| dep.name.clone())
+                    .collect(),
+                dependency_kinds: HashMap::new(),
+                dependency_targets: HashMap::new(),
+            })
+            .collect();
+
+        let source_vcs = info.packages.first().and_then(|root| match &root.source {
+            AuditSource::Git(url) => Some(LocalVcsRef {
+                url: url.clone(),
+                commit: None,
+                tag: None,
+            }),
+            _ => None,
+        });
+
+        HarvesterBatch {
+            nodes,
+            vulnerabilities: vec![],
+            source_vcs,
+        }
+    }
+}
+
+/// Classifies where `pkg` (at `index` in the deterministic ordering) came
+/// from. The root (`index == 0`) is `Git` when the batch recorded a VCS ref,
+/// otherwise `Local`; every other package is assumed to come from the
+/// ecosystem's standard registry.
+fn package_source(
+    index: usize,
+    pkg: &LocalPackageNode,
+    source_vcs: &Option<LocalVcsRef>,
+) -> AuditSource {
+    if index == 0 {
+        return match source_vcs {
+            Some(vcs) => AuditSource::Git(vcs.url.clone()),
+            None => AuditSource::Local,
+        };
+    }
+
+    if pkg.ecosystem == "cargo" {
+        AuditSource::CratesIo
+    } else {
+        AuditSource::Registry(pkg.ecosystem.clone())
+    }
+}
+
+/// Breadth-first traversal over `edges` (an adjacency list by index, each
+/// target tagged with its edge kind), starting at index `0`, returning every
+/// index reachable from it.
+fn reachable_from_root(edges: &[Vec<(usize, EdgeKind)>]) -> HashSet<usize> {
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::new();
+    queue.push_back(0);
+
+    while let Some(index) = queue.pop_front() {
+        if !visited.insert(index) {
+            continue;
+        }
+        if let Some(neighbors) = edges.get(index) {
+            for &(next, _) in neighbors {
+                if !visited.contains(&next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+
+    visited
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(name: &str, version: &str, ecosystem: &str, deps: &[&str]) -> LocalPackageNode {
+        node_with_kinds(name, version, ecosystem, deps, &[])
+    }
+
+    fn node_with_kinds(
+        name: &str,
+        version: &str,
+        ecosystem: &str,
+        deps: &[&str],
+        kinds: &[(&str, EdgeKind)],
+    ) -> LocalPackageNode {
+        LocalPackageNode {
+            name: name.to_string(),
+            version: version.to_string(),
+            ecosystem: ecosystem.to_string(),
+            description: None,
+            license: None,
+            dependencies: deps.iter().map(|d| d.to_string()).collect(),
+            dependency_kinds: kinds
+                .iter()
+                .map(|(name, kind)| (name.to_string(), *kind))
+                .collect(),
+            dependency_targets: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_to_audit_info_places_root_first_and_sorts_the_rest() {
+        let batch = HarvesterBatch {
+            nodes: vec![
+                node("root", "0.1.0", "cargo", &["zed ^1", "anyhow ^1"]),
+                node("zed", "1.0.0", "cargo", &[]),
+                node("anyhow", "1.0.0", "cargo", &[]),
+            ],
+            vulnerabilities: vec![],
+            source_vcs: None,
+        };
+
+        let info = batch.to_audit_info();
+        let names: Vec<&str> = info.packages.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["root", "anyhow", "zed"]);
+        assert_eq!(info.packages[0].kind, AuditKind::Runtime);
+    }
+
+    #[test]
+    fn test_to_audit_info_deduplicates_by_name_and_version() {
+        let batch = HarvesterBatch {
+            nodes: vec![
+                node("root", "0.1.0", "cargo", &["serde ^1"]),
+                node("serde", "1.0.0", "cargo", &[]),
+                node("serde", "1.0.0", "cargo", &[]),
+            ],
+            vulnerabilities: vec![],
+            source_vcs: None,
+        };
+
+        let info = batch.to_audit_info();
+        assert_eq!(info.packages.len(), 2);
+    }
+
+    #[test]
+    fn test_to_audit_info_marks_unreachable_packages_as_development() {
+        let batch = HarvesterBatch {
+            nodes: vec![
+                node("root", "0.1.0", "cargo", &["serde ^1"]),
+                node("serde", "1.0.0", "cargo", &[]),
+                node("orphan", "0.1.0", "cargo", &[]),
+            ],
+            vulnerabilities: vec![],
+            source_vcs: None,
+        };
+
+        let info = batch.to_audit_info();
+        let orphan = info.packages.iter().find(|p| p.name == "orphan").unwrap();
+        assert_eq!(orphan.kind, AuditKind::Development);
+        let serde = info.packages.iter().find(|p| p.name == "serde").unwrap();
+        assert_eq!(serde.kind, AuditKind::Runtime);
+    }
+
+    #[test]
+    fn test_to_audit_info_root_source_is_git_when_vcs_present() {
+        let batch = HarvesterBatch {
+            nodes: vec![node("root", "0.1.0", "cargo", &[])],
+            vulnerabilities: vec![],
+            source_vcs: Some(LocalVcsRef {
+                url: "https://example.com/root".to_string(),
+                commit: Some("abc123".to_string()),
+                tag: None,
+            }),
+        };
+
+        let info = batch.to_audit_info();
+        assert_eq!(
+            info.packages[0].source,
+            AuditSource::Git("https://example.com/root".to_string())
+        );
+    }
+
+    #[test]
+    fn test_audit_kind_ord_prefers_more_permissive() {
+        assert_eq!(
+            AuditKind::Runtime.max(AuditKind::Development),
+            AuditKind::Runtime
+        );
+        assert_eq!(
+            AuditKind::Build.max(AuditKind::Development),
+            AuditKind::Build
+        );
+    }
+
+    #[test]
+    fn test_to_audit_info_classifies_build_only_edge_as_build() {
+        let batch = HarvesterBatch {
+            nodes: vec![
+                node_with_kinds(
+                    "root",
+                    "0.1.0",
+                    "cargo",
+                    &["cc ^1"],
+                    &[("cc", EdgeKind::Build)],
+                ),
+                node("cc", "1.0.0", "cargo", &[]),
+            ],
+            vulnerabilities: vec![],
+            source_vcs: None,
+        };
+
+        let info = batch.to_audit_info();
+        let cc = info.packages.iter().find(|p| p.name == "cc").unwrap();
+        assert_eq!(cc.kind, AuditKind::Build);
+    }
+
+    #[test]
+    fn test_to_audit_info_prefers_runtime_when_also_reached_normally() {
+        let batch = HarvesterBatch {
+            nodes: vec![
+                node_with_kinds(
+                    "root",
+                    "0.1.0",
+                    "cargo",
+                    &["cc ^1", "helper ^1"],
+                    &[("cc", EdgeKind::Build)],
+                ),
+                node("cc", "1.0.0", "cargo", &[]),
+                node("helper", "0.1.0", "cargo", &["cc ^1"]),
+            ],
+            vulnerabilities: vec![],
+            source_vcs: None,
+        };
+
+        let info = batch.to_audit_info();
+        let cc = info.packages.iter().find(|p| p.name == "cc").unwrap();
+        assert_eq!(cc.kind, AuditKind::Runtime);
+    }
+
+    #[test]
+    fn test_round_trip_through_audit_info_preserves_structure() {
+        let batch = HarvesterBatch {
+            nodes: vec![
+                node("root", "0.1.0", "cargo", &["serde ^1"]),
+                node("serde", "1.0.0", "cargo", &[]),
+            ],
+            vulnerabilities: vec![],
+            source_vcs: None,
+        };
+
+        let info = batch.to_audit_info();
+        let roundtripped = HarvesterBatch::from_audit_info(&info);
+
+        assert_eq!(roundtripped.nodes.len(), 2);
+        assert_eq!(roundtripped.nodes[0].name, "root");
+        assert_eq!(
+            roundtripped.nodes[0].dependencies,
+            vec!["serde".to_string()]
+        );
+    }
+}
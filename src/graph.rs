@@ -0,0 +1,355 @@
+//! Resolved dependency graph over a [`HarvesterBatch`].
+//!
+//! [`HarvesterBatch`] only stores `nodes` and each node's dependencies as
+//! flat name strings, which makes traversal, reverse lookups, and cycle
+//! detection impossible without re-deriving the graph every time. A
+//! [`DependencyGraph`] interns every node into an arena and resolves
+//! dependency strings into [`NodeId`]s once, up front, so analyzers can
+//! reason about impact and vulnerability blast-radius directly.
+
+use crate::model::{HarvesterBatch, LocalPackageNode};
+use std::collections::{HashMap, VecDeque};
+use thiserror::Error;
+
+/// Index of a node in a [`DependencyGraph`]'s arena.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(pub usize);
+
+/// A resolved dependency graph: every [`LocalPackageNode`] interned into an
+/// arena, with forward and (precomputed) reverse adjacency built by
+/// resolving each dependency string to a [`NodeId`] by name.
+#[derive(Debug, Clone)]
+pub struct DependencyGraph {
+    nodes: Vec<LocalPackageNode>,
+    edges: Vec<Vec<NodeId>>,
+    reverse_edges: Vec<Vec<NodeId>>,
+
+    /// Dependency strings that didn't match any node in the batch (e.g. a
+    /// dependency outside the harvested set), tagged with the node that
+    /// declared them.
+    unresolved: Vec<(NodeId, String)>,
+}
+
+/// A cycle found while computing a [`DependencyGraph::topological_order`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("dependency cycle detected: {cycle:?}")]
+pub struct CycleError {
+    /// One participating cycle, for diagnostics.
+    pub cycle: Vec<NodeId>,
+}
+
+impl DependencyGraph {
+    /// Builds a graph from `batch`, resolving each node's dependency strings
+    /// (`"name req"`, per [`LocalPackageNode::dependencies`]) to a
+    /// [`NodeId`] by matching the leading name against the batch's nodes.
+    /// Dependencies that don't match any node are recorded in
+    /// [`DependencyGraph::unresolved_dependencies`] instead of being
+    /// dropped. When multiple nodes share a name, the first one (in batch
+    /// order) is used, matching the convention used elsewhere in this
+    /// crate for resolving `LocalPackageNode` name ambiguity.
+    pub fn from_batch(batch: &HarvesterBatch) -> Self {
+        let nodes = batch.nodes.clone();
+
+        let mut index_by_name: HashMap<&str, usize> = HashMap::new();
+        for (index, node) in nodes.iter().enumerate() {
+            index_by_name.entry(node.name.as_str()).or_insert(index);
+        }
+
+        let mut edges = vec![Vec::new(); nodes.len()];
+        let mut unresolved = Vec::new();
+
+        for (index, node) in nodes.iter().enumerate() {
+            for dep in &node.dependencies {
+                let dep_name = dep.split_whitespace().next().unwrap_or(dep);
+                match index_by_name.get(dep_name) {
+                    Some(&target) => edges[index].push(NodeId(target)),
+                    None => unresolved.push((NodeId(index), dep_name.to_string())),
+                }
+            }
+        }
+
+        let mut reverse_edges = vec![Vec::new(); nodes.len()];
+        for (index, targets) in edges.iter().enumerate() {
+            for target in targets {
+                reverse_edges[target.0].push(NodeId(index));
+            }
+        }
+
+        Self {
+            nodes,
+            edges,
+            reverse_edges,
+            unresolved,
+        }
+    }
+
+    /// Number of nodes in the graph.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Whether the graph has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// The node at `id`, if it's in range.
+    pub fn node(&self, id: NodeId) -> Option<&LocalPackageNode> {
+        self.nodes.get(id.0)
+    }
+
+    /// Dependencies of `id` (forward adjacency).
+    pub fn dependencies(&self, id: NodeId) -> &[NodeId] {
+        self.edges.get(id.0).map_or(&[], Vec::as_slice)
+    }
+
+    /// Nodes that depend on `id` (precomputed inverse adjacency).
+    pub fn reverse_dependencies(&self, id: NodeId) -> Vec<NodeId> {
+        self.reverse_edges.get(id.0).cloned().unwrap_or_default()
+    }
+
+    /// Dependency strings that couldn't be resolved to a node in this
+    /// batch, each tagged with the node that declared them.
+    pub fn unresolved_dependencies(&self) -> &[(NodeId, String)] {
+        &self.unresolved
+    }
+
+    /// Computes a topological order over the graph using Kahn's algorithm:
+    /// repeatedly remove zero-in-degree nodes until none remain. If any
+    /// nodes are left unremoved, the graph has a cycle.
+    pub fn topological_order(&self) -> Result<Vec<NodeId>, CycleError> {
+        let mut in_degree = vec![0usize; self.nodes.len()];
+        for targets in &self.edges {
+            for target in targets {
+                in_degree[target.0] += 1;
+            }
+        }
+
+        let mut queue: VecDeque<usize> = (0..self.nodes.len())
+            .filter(|&index| in_degree[index] == 0)
+            .collect();
+
+        let mut order = Vec::with_capacity(self.nodes.len());
+        while let Some(index) = queue.pop_front() {
+            order.push(NodeId(index));
+            for target in &self.edges[index] {
+                in_degree[target.0] -= 1;
+                if in_degree[target.0] == 0 {
+                    queue.push_back(target.0);
+                }
+            }
+        }
+
+        if order.len() == self.nodes.len() {
+            Ok(order)
+        } else {
+            let cycle = self.find_cycles().into_iter().next().unwrap_or_default();
+            Err(CycleError { cycle })
+        }
+    }
+
+    /// Finds every cycle (non-trivial strongly-connected component, or a
+    /// single node with a self-loop) via an iterative Tarjan's SCC
+    /// algorithm, so it stays safe on deep graphs that would overflow the
+    /// stack with a recursive implementation.
+    pub fn find_cycles(&self) -> Vec<Vec<NodeId>> {
+        struct Frame {
+            node: usize,
+            next_child: usize,
+        }
+
+        let n = self.nodes.len();
+        let mut index: Vec<Option<usize>> = vec![None; n];
+        let mut lowlink = vec![0usize; n];
+        let mut on_stack = vec![false; n];
+        let mut tarjan_stack = Vec::new();
+        let mut next_index = 0usize;
+        let mut sccs = Vec::new();
+
+        for start in 0..n {
+            if index[start].is_some() {
+                continue;
+            }
+
+            let mut call_stack = vec![Frame {
+                node: start,
+                next_child: 0,
+            }];
+            index[start] = Some(next_index);
+            lowlink[start] = next_index;
+            next_index += 1;
+            tarjan_stack.push(start);
+            on_stack[start] = true;
+
+            while let Some(frame) = call_stack.last_mut() {
+                let v = frame.node;
+
+                if frame.next_child < self.edges[v].len() {
+                    let w = self.edges[v][frame.next_child].0;
+                    frame.next_child += 1;
+
+                    match index[w] {
+                        None => {
+                            index[w] = Some(next_index);
+                            lowlink[w] = next_index;
+                            next_index += 1;
+                            tarjan_stack.push(w);
+                            on_stack[w] = true;
+                            call_stack.push(Frame {
+                                node: w,
+                                next_child: 0,
+                            });
+                        }
+                        Some(w_index) if on_stack[w] => {
+                            lowlink[v] = lowlink[v].min(w_index);
+                        }
+                        Some(_) => {}
+                    }
+                } else {
+                    call_stack.pop();
+                    if let Some(parent_frame) = call_stack.last() {
+                        let parent = parent_frame.node;
+                        lowlink[parent] = lowlink[parent].min(lowlink[v]);
+                    }
+
+                    if lowlink[v] == index[v].expect("v was indexed on entry") {
+                        let mut scc = Vec::new();
+                        loop {
+                            let w = tarjan_stack.pop().expect("v is still on the stack");
+                            on_stack[w] = false;
+                            scc.push(NodeId(w));
+                            if w == v {
+                                break;
+                            }
+                        }
+                        sccs.push(scc);
+                    }
+                }
+            }
+        }
+
+        sccs.into_iter()
+            .filter(|scc| {
+                scc.len() > 1 || self.edges[scc[0].0].iter().any(|target| *target == scc[0])
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(name: &str, deps: &[&str]) -> LocalPackageNode {
+        LocalPackageNode {
+            name: name.to_string(),
+            version: "1.0.0".to_string(),
+            ecosystem: "cargo".to_string(),
+            description: None,
+            license: None,
+            dependencies: deps.iter().map(|d| d.to_string()).collect(),
+            dependency_kinds: HashMap::new(),
+            dependency_targets: HashMap::new(),
+        }
+    }
+
+    fn batch(nodes: Vec<LocalPackageNode>) -> HarvesterBatch {
+        HarvesterBatch {
+            nodes,
+            vulnerabilities: vec![],
+            source_vcs: None,
+        }
+    }
+
+    #[test]
+    fn test_from_batch_resolves_edges_by_name() {
+        let graph = DependencyGraph::from_batch(&batch(vec![
+            node("root", &["serde ^1.0"]),
+            node("serde", &[]),
+        ]));
+
+        assert_eq!(graph.dependencies(NodeId(0)), &[NodeId(1)]);
+        assert!(graph.unresolved_dependencies().is_empty());
+    }
+
+    #[test]
+    fn test_from_batch_records_unresolved_external_dependencies() {
+        let graph = DependencyGraph::from_batch(&batch(vec![node("root", &["ghost ^1.0"])]));
+
+        assert_eq!(
+            graph.unresolved_dependencies(),
+            &[(NodeId(0), "ghost".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_reverse_dependencies_is_the_inverse_of_dependencies() {
+        let graph = DependencyGraph::from_batch(&batch(vec![
+            node("root", &["serde ^1.0", "anyhow ^1.0"]),
+            node("serde", &[]),
+            node("anyhow", &[]),
+        ]));
+
+        assert_eq!(graph.reverse_dependencies(NodeId(1)), vec![NodeId(0)]);
+        assert_eq!(graph.reverse_dependencies(NodeId(2)), vec![NodeId(0)]);
+        assert!(graph.reverse_dependencies(NodeId(0)).is_empty());
+    }
+
+    #[test]
+    fn test_topological_order_respects_dependency_edges() {
+        let graph = DependencyGraph::from_batch(&batch(vec![
+            node("root", &["mid ^1.0"]),
+            node("mid", &["leaf ^1.0"]),
+            node("leaf", &[]),
+        ]));
+
+        let order = graph.topological_order().unwrap();
+        let position = |id: NodeId| order.iter().position(|&n| n == id).unwrap();
+
+        assert!(position(NodeId(0)) < position(NodeId(1)));
+        assert!(position(NodeId(1)) < position(NodeId(2)));
+    }
+
+    #[test]
+    fn test_topological_order_errors_with_cycle_on_cyclic_graph() {
+        let graph = DependencyGraph::from_batch(&batch(vec![
+            node("a", &["b ^1.0"]),
+            node("b", &["a ^1.0"]),
+        ]));
+
+        let err = graph.topological_order().unwrap_err();
+        assert_eq!(err.cycle.len(), 2);
+    }
+
+    #[test]
+    fn test_find_cycles_detects_multi_node_scc() {
+        let graph = DependencyGraph::from_batch(&batch(vec![
+            node("a", &["b ^1.0"]),
+            node("b", &["c ^1.0"]),
+            node("c", &["a ^1.0"]),
+            node("isolated", &[]),
+        ]));
+
+        let cycles = graph.find_cycles();
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].len(), 3);
+    }
+
+    #[test]
+    fn test_find_cycles_detects_self_loop() {
+        let graph = DependencyGraph::from_batch(&batch(vec![node("self-dep", &["self-dep ^1.0"])]));
+
+        let cycles = graph.find_cycles();
+        assert_eq!(cycles, vec![vec![NodeId(0)]]);
+    }
+
+    #[test]
+    fn test_find_cycles_empty_for_acyclic_graph() {
+        let graph = DependencyGraph::from_batch(&batch(vec![
+            node("root", &["leaf ^1.0"]),
+            node("leaf", &[]),
+        ]));
+
+        assert!(graph.find_cycles().is_empty());
+    }
+}
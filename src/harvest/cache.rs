@@ -0,0 +1,154 @@
+//! Incremental harvest cache — hash-and-skip for unchanged packages.
+//!
+//! Mirrors the hash-and-skip behavior of a task runner: before extraction,
+//! the input package file is streamed through SHA-256 and combined with its
+//! size to form a cache key. A hit returns the previously-computed
+//! [`HarvestMetadata`] straight away, skipping extraction, analysis, and
+//! hashing entirely.
+
+use crate::harvest::traits::HarvestMetadata;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Computes the cache key for a package file: a streaming SHA-256 digest of
+/// its bytes, combined with its size on disk.
+///
+/// Reading `path` happens synchronously here — callers on an async runtime
+/// should run this inside `spawn_blocking`.
+pub fn compute_cache_key(path: &Path) -> io::Result<String> {
+    let mut file = fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buf = [0u8; 64 * 1024];
+
+    loop {
+        let read = file.read(&mut buf)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buf[..read]);
+    }
+
+    let size_bytes = file.metadata()?.len();
+    Ok(format!("{}-{}", hex::encode(hasher.finalize()), size_bytes))
+}
+
+/// A cached harvest outcome, tagged with the harvester version that
+/// produced it so a crate bump can invalidate stale entries.
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    harvester_version: String,
+    metadata: HarvestMetadata,
+}
+
+/// Persistent on-disk index of prior [`HarvestMetadata`] keyed by cache key.
+///
+/// Each entry is stored as its own JSON file under `root`, named by key —
+/// simple, inspectable, and safe for concurrent readers since writes are
+/// whole-file replacements.
+pub struct HarvestCache {
+    root: PathBuf,
+}
+
+impl HarvestCache {
+    /// Opens (creating if needed) a cache rooted at `root`.
+    pub fn open(root: impl Into<PathBuf>) -> io::Result<Self> {
+        let root = root.into();
+        fs::create_dir_all(&root)?;
+        Ok(Self { root })
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.root.join(format!("{key}.json"))
+    }
+
+    /// Looks up `key`, returning the cached metadata only if it was
+    /// produced by exactly `current_harvester_version` — any other version
+    /// is treated as a miss, forcing re-analysis.
+    pub fn get(&self, key: &str, current_harvester_version: &str) -> Option<HarvestMetadata> {
+        let bytes = fs::read(self.entry_path(key)).ok()?;
+        let entry: CacheEntry = serde_json::from_slice(&bytes).ok()?;
+
+        if entry.harvester_version != current_harvester_version {
+            return None;
+        }
+
+        Some(entry.metadata)
+    }
+
+    /// Stores `metadata` under `key`, tagged with its own `harvester_version`
+    /// so a later crate bump invalidates it automatically.
+    pub fn put(&self, key: &str, metadata: &HarvestMetadata) -> io::Result<()> {
+        let entry = CacheEntry {
+            harvester_version: metadata.harvester_version.clone(),
+            metadata: metadata.clone(),
+        };
+        let bytes = serde_json::to_vec_pretty(&entry)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(self.entry_path(key), bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn sample_metadata(version: &str) -> HarvestMetadata {
+        HarvestMetadata {
+            source_format: "test".to_string(),
+            package_name: "demo".to_string(),
+            version: "1.0.0".to_string(),
+            dependencies: vec![],
+            files: vec![],
+            capabilities: vec![],
+            harvest_timestamp: 0,
+            harvester_version: version.to_string(),
+            diagnostics: vec![],
+            extra: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_cache_round_trip() {
+        let dir = std::env::temp_dir().join(format!("harvest_cache_test_{}", std::process::id()));
+        let cache = HarvestCache::open(&dir).unwrap();
+
+        cache.put("abc123-10", &sample_metadata("1.2.3")).unwrap();
+        let hit = cache.get("abc123-10", "1.2.3");
+
+        assert!(hit.is_some());
+        assert_eq!(hit.unwrap().package_name, "demo");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_cache_invalidated_by_version_bump() {
+        let dir =
+            std::env::temp_dir().join(format!("harvest_cache_test_version_{}", std::process::id()));
+        let cache = HarvestCache::open(&dir).unwrap();
+
+        cache.put("abc123-10", &sample_metadata("1.2.3")).unwrap();
+        let miss = cache.get("abc123-10", "1.3.0");
+
+        assert!(miss.is_none());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_compute_cache_key_is_stable_for_same_content() {
+        let path = std::env::temp_dir().join(format!("cache_key_test_{}.bin", std::process::id()));
+        std::fs::write(&path, b"identical content").unwrap();
+
+        let first = compute_cache_key(&path).unwrap();
+        let second = compute_cache_key(&path).unwrap();
+
+        assert_eq!(first, second);
+
+        std::fs::remove_file(&path).ok();
+    }
+}
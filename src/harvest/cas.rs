@@ -0,0 +1,343 @@
+//! Content-addressable storage (CAS) for deduplicated file bodies.
+//!
+//! Every unique file body written to a [`ContentStore`] is named by a
+//! self-describing multihash of its contents: `<varint hash-code><varint
+//! digest-len><digest bytes>`, hex-encoded and sharded onto disk as
+//! `objects/<first 2 hex chars>/<remaining hex chars>`. Writing is
+//! idempotent — if the target path already exists the bytes are assumed
+//! identical (same digest, same content) and the write is skipped, which is
+//! what gives cross-package deduplication for free.
+
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+/// Multihash function code for SHA-256, per the multiformats table.
+const SHA2_256_CODE: u64 = 0x12;
+
+/// Encodes a digest as a self-describing multihash: `<code><len><digest>`,
+/// each of `code` and `len` written as an unsigned varint (LEB128).
+pub fn encode_multihash(code: u64, digest: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(digest.len() + 2);
+    write_uvarint(&mut buf, code);
+    write_uvarint(&mut buf, digest.len() as u64);
+    buf.extend_from_slice(digest);
+    buf
+}
+
+/// Decodes a self-describing multihash, returning `(code, digest)`.
+pub fn decode_multihash(bytes: &[u8]) -> Option<(u64, &[u8])> {
+    let (code, code_len) = read_uvarint(bytes)?;
+    let (digest_len, len_len) = read_uvarint(&bytes[code_len..])?;
+    let digest_start = code_len + len_len;
+    let digest_end = digest_start.checked_add(digest_len as usize)?;
+    let digest = bytes.get(digest_start..digest_end)?;
+    Some((code, digest))
+}
+
+fn write_uvarint(buf: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let mut byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        buf.push(byte);
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+fn read_uvarint(bytes: &[u8]) -> Option<(u64, usize)> {
+    let mut result: u64 = 0;
+    let mut shift = 0u32;
+    for (i, &b) in bytes.iter().enumerate() {
+        result |= ((b & 0x7f) as u64) << shift;
+        if b & 0x80 == 0 {
+            return Some((result, i + 1));
+        }
+        shift += 7;
+        if shift > 63 {
+            return None;
+        }
+    }
+    None
+}
+
+/// Computes the hex-encoded multihash address of a block's content.
+pub fn block_address(bytes: &[u8]) -> String {
+    let digest = Sha256::digest(bytes);
+    hex::encode(encode_multihash(SHA2_256_CODE, &digest))
+}
+
+/// Tracked metadata for a block already present in the store.
+struct CasEntry {
+    size: u64,
+    last_access: SystemTime,
+}
+
+/// Outcome of writing a block to the store.
+#[derive(Debug, Clone)]
+pub struct PutOutcome {
+    /// Hex-encoded multihash address of the block.
+    pub address: String,
+
+    /// `true` if the block was already present (write skipped).
+    pub deduplicated: bool,
+
+    /// Bytes actually written to disk (`0` when deduplicated).
+    pub bytes_written: u64,
+}
+
+/// A disk-backed, content-addressed block store with a byte budget.
+///
+/// Blocks are keyed by their multihash address and sharded two-hex-chars
+/// deep under `<root>/objects/`. When a write would exceed `max_bytes`, the
+/// least-recently-accessed blocks are evicted first (a `max_bytes` of `0`
+/// disables the budget entirely).
+///
+/// # Thread Safety
+///
+/// Safe to share across threads via `Arc<ContentStore>`; the in-memory
+/// access index is guarded by a [`Mutex`].
+pub struct ContentStore {
+    root: PathBuf,
+    max_bytes: u64,
+    index: Mutex<HashMap<String, CasEntry>>,
+    used_bytes: AtomicU64,
+}
+
+impl ContentStore {
+    /// Opens (or creates) a content store rooted at `root`, rebuilding its
+    /// access index from whatever blocks already exist on disk.
+    pub fn open(root: impl Into<PathBuf>, max_bytes: u64) -> io::Result<Self> {
+        let root = root.into();
+        fs::create_dir_all(root.join("objects"))?;
+
+        let mut index = HashMap::new();
+        let mut used_bytes = 0u64;
+
+        if let Ok(shards) = fs::read_dir(root.join("objects")) {
+            for shard in shards.flatten() {
+                let shard_name = match shard.file_name().into_string() {
+                    Ok(name) => name,
+                    Err(_) => continue, // non-UTF8 shard dir; not ours, skip
+                };
+                let Ok(entries) = fs::read_dir(shard.path()) else {
+                    continue;
+                };
+                for entry in entries.flatten() {
+                    let Ok(meta) = entry.metadata() else {
+                        continue;
+                    };
+                    if !meta.is_file() {
+                        continue;
+                    }
+                    let Some(rest) = entry.file_name().to_str().map(str::to_string) else {
+                        continue;
+                    };
+                    let address = format!("{shard_name}{rest}");
+                    let last_access = meta
+                        .accessed()
+                        .or_else(|_| meta.modified())
+                        .unwrap_or_else(|_| SystemTime::now());
+                    used_bytes += meta.len();
+                    index.insert(
+                        address,
+                        CasEntry {
+                            size: meta.len(),
+                            last_access,
+                        },
+                    );
+                }
+            }
+        }
+
+        Ok(Self {
+            root,
+            max_bytes,
+            index: Mutex::new(index),
+            used_bytes: AtomicU64::new(used_bytes),
+        })
+    }
+
+    /// Writes `bytes` to the store, returning its multihash address.
+    ///
+    /// Idempotent: if a block with this address already exists its access
+    /// time is refreshed and the write is skipped — the bytes are assumed
+    /// identical since the address is a content hash. The in-memory check,
+    /// on-disk check, and write-plus-accounting all happen under a single
+    /// hold of `index`'s lock, so two concurrent `put`s of identical content
+    /// can't both miss the dedup checks and double-count the block.
+    pub fn put(&self, bytes: &[u8]) -> io::Result<PutOutcome> {
+        let address = block_address(bytes);
+        let path = self.shard_path(&address);
+
+        let mut index = self.index.lock().unwrap();
+
+        if let Some(entry) = index.get_mut(&address) {
+            entry.last_access = SystemTime::now();
+            return Ok(PutOutcome {
+                address,
+                deduplicated: true,
+                bytes_written: 0,
+            });
+        }
+
+        if path.exists() {
+            // Present on disk but not yet in our in-memory index (e.g.
+            // written by another process sharing the store). Adopt it.
+            self.used_bytes.fetch_add(bytes.len() as u64, Ordering::SeqCst);
+            index.insert(
+                address.clone(),
+                CasEntry {
+                    size: bytes.len() as u64,
+                    last_access: SystemTime::now(),
+                },
+            );
+            return Ok(PutOutcome {
+                address,
+                deduplicated: true,
+                bytes_written: 0,
+            });
+        }
+
+        self.evict_to_fit(bytes.len() as u64, &mut index);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, bytes)?;
+
+        let size = bytes.len() as u64;
+        self.used_bytes.fetch_add(size, Ordering::SeqCst);
+        index.insert(
+            address.clone(),
+            CasEntry {
+                size,
+                last_access: SystemTime::now(),
+            },
+        );
+
+        Ok(PutOutcome {
+            address,
+            deduplicated: false,
+            bytes_written: size,
+        })
+    }
+
+    /// Total bytes currently tracked in the store.
+    pub fn used_bytes(&self) -> u64 {
+        self.used_bytes.load(Ordering::SeqCst)
+    }
+
+    fn shard_path(&self, address: &str) -> PathBuf {
+        let split_at = address.len().min(2);
+        let (shard, rest) = address.split_at(split_at);
+        self.root.join("objects").join(shard).join(rest)
+    }
+
+    /// Evicts least-recently-accessed blocks until `incoming` more bytes
+    /// would fit under `max_bytes` (a no-op when the budget is `0`/unbounded).
+    /// Takes `index` already locked by the caller, rather than locking it
+    /// itself, so it can run as part of a single `put` critical section.
+    fn evict_to_fit(&self, incoming: u64, index: &mut HashMap<String, CasEntry>) {
+        if self.max_bytes == 0 {
+            return;
+        }
+
+        loop {
+            if self.used_bytes.load(Ordering::SeqCst) + incoming <= self.max_bytes {
+                return;
+            }
+
+            let victim = index
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_access)
+                .map(|(address, _)| address.clone());
+
+            let Some(address) = victim else {
+                // Nothing left to evict; a single oversized block can still
+                // leave us over budget, which is acceptable — we never fail
+                // a write solely for exceeding the soft budget.
+                return;
+            };
+
+            let size = index.remove(&address).map(|entry| entry.size).unwrap_or(0);
+            let _ = fs::remove_file(self.shard_path(&address));
+            self.used_bytes.fetch_sub(size, Ordering::SeqCst);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multihash_round_trip() {
+        let digest = Sha256::digest(b"hello world");
+        let encoded = encode_multihash(SHA2_256_CODE, &digest);
+        let (code, decoded_digest) = decode_multihash(&encoded).unwrap();
+        assert_eq!(code, SHA2_256_CODE);
+        assert_eq!(decoded_digest, digest.as_slice());
+    }
+
+    #[test]
+    fn test_put_is_idempotent() {
+        let dir = std::env::temp_dir().join(format!("cas_test_{}", std::process::id()));
+        let store = ContentStore::open(&dir, 0).unwrap();
+
+        let first = store.put(b"same bytes").unwrap();
+        assert!(!first.deduplicated);
+
+        let second = store.put(b"same bytes").unwrap();
+        assert!(second.deduplicated);
+        assert_eq!(first.address, second.address);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_concurrent_put_of_same_content_is_deduplicated_exactly_once() {
+        use std::sync::Arc;
+        use std::thread;
+
+        let dir = std::env::temp_dir().join(format!("cas_test_concurrent_{}", std::process::id()));
+        let store = Arc::new(ContentStore::open(&dir, 0).unwrap());
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let store = Arc::clone(&store);
+                thread::spawn(move || store.put(b"racing bytes").unwrap())
+            })
+            .collect();
+
+        let outcomes: Vec<PutOutcome> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        assert_eq!(outcomes.iter().filter(|o| !o.deduplicated).count(), 1);
+        assert_eq!(store.used_bytes(), "racing bytes".len() as u64);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_eviction_respects_budget() {
+        let dir = std::env::temp_dir().join(format!("cas_test_evict_{}", std::process::id()));
+        // Budget fits roughly one block; writing a second must evict the first.
+        let store = ContentStore::open(&dir, 16).unwrap();
+
+        store.put(b"0123456789ABCDEF").unwrap(); // 16 bytes, fills the budget exactly
+        let used_after_first = store.used_bytes();
+        store.put(b"FEDCBA9876543210").unwrap();
+
+        assert!(store.used_bytes() <= used_after_first);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,134 @@
+//! Structured, positioned diagnostics for pipeline stages, in the spirit of
+//! Cargo's compiler-message JSON.
+//!
+//! A [`Diagnostic`] lets a stage report a non-fatal problem (a missing
+//! optional desktop key, an unparseable dependency line) as a warning
+//! instead of either swallowing it or aborting
+//! [`PackageFormat::analyze`](crate::harvest::traits::PackageFormat::analyze);
+//! [`to_json_lines`] streams a whole set of them for callers that want rich,
+//! positioned findings per package.
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// How serious a [`Diagnostic`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+    Note,
+}
+
+/// A location within a file that a [`Diagnostic`] points at.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiagnosticSpan {
+    /// Path to the file the diagnostic is about, relative to the
+    /// extraction root.
+    pub file: PathBuf,
+
+    /// Byte range within `file`, when known.
+    pub range: Option<(usize, usize)>,
+}
+
+impl DiagnosticSpan {
+    /// A span covering all of `file`, with no specific byte range.
+    pub fn new(file: impl Into<PathBuf>) -> Self {
+        Self {
+            file: file.into(),
+            range: None,
+        }
+    }
+
+    /// Narrows this span to the byte range `[start, end)`.
+    pub fn with_range(mut self, start: usize, end: usize) -> Self {
+        self.range = Some((start, end));
+        self
+    }
+}
+
+/// A single positioned, severity-tagged finding from a pipeline stage.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    pub spans: Vec<DiagnosticSpan>,
+    pub help: Option<String>,
+}
+
+impl Diagnostic {
+    /// Builds a diagnostic with no spans or help text.
+    pub fn new(severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            message: message.into(),
+            spans: Vec::new(),
+            help: None,
+        }
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(Severity::Error, message)
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(Severity::Warning, message)
+    }
+
+    pub fn note(message: impl Into<String>) -> Self {
+        Self::new(Severity::Note, message)
+    }
+
+    pub fn with_span(mut self, span: DiagnosticSpan) -> Self {
+        self.spans.push(span);
+        self
+    }
+
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+}
+
+/// Serializes `diagnostics` as newline-delimited JSON, one [`Diagnostic`]
+/// per line.
+pub fn to_json_lines(diagnostics: &[Diagnostic]) -> Result<String, serde_json::Error> {
+    diagnostics
+        .iter()
+        .map(serde_json::to_string)
+        .collect::<Result<Vec<_>, _>>()
+        .map(|lines| lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diagnostic_builder_sets_spans_and_help() {
+        let diagnostic = Diagnostic::warning("unparseable dependency line")
+            .with_span(DiagnosticSpan::new("Cargo.toml").with_range(12, 30))
+            .with_help("expected a semver range");
+
+        assert_eq!(diagnostic.severity, Severity::Warning);
+        assert_eq!(diagnostic.spans.len(), 1);
+        assert_eq!(diagnostic.spans[0].range, Some((12, 30)));
+        assert_eq!(diagnostic.help.as_deref(), Some("expected a semver range"));
+    }
+
+    #[test]
+    fn test_to_json_lines_emits_one_line_per_diagnostic() {
+        let diagnostics = vec![
+            Diagnostic::error("boom"),
+            Diagnostic::note("fyi").with_span(DiagnosticSpan::new("manifest.toml")),
+        ];
+
+        let jsonl = to_json_lines(&diagnostics).unwrap();
+        let lines: Vec<&str> = jsonl.lines().collect();
+        assert_eq!(lines.len(), 2);
+
+        let first: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(first["severity"], "error");
+        assert_eq!(first["message"], "boom");
+    }
+}
@@ -0,0 +1,137 @@
+//! Hashing stage — populates [`FileDescriptor::hash`](crate::harvest::traits::FileDescriptor::hash)
+//! and feeds file bodies into a [`ContentStore`].
+//!
+//! This is the third stage in the pipeline (Extraction → Analysis →
+//! Hashing), mirroring [`Extractor`](crate::harvest::pipeline::Extractor)
+//! and [`Analyzer`](crate::harvest::pipeline::Analyzer): it consumes the
+//! previous stage's output and the still-live [`TempExtraction`], and hands
+//! back normalized output for the next one.
+
+use crate::harvest::cas::ContentStore;
+use crate::harvest::pipeline::TempExtraction;
+use crate::harvest::traits::HarvestMetadata;
+use std::error::Error;
+use std::fs;
+use std::sync::Arc;
+
+/// Output of a [`Hasher`] stage.
+pub struct HashOutcome {
+    /// Metadata as left by the hasher: [`CasHasher`] populates every
+    /// non-symlink [`FileDescriptor::hash`](crate::harvest::traits::FileDescriptor::hash);
+    /// [`NoopHasher`] leaves every `hash` as `None`.
+    pub metadata: HarvestMetadata,
+
+    /// Bytes that did not need to be written because an identical block was
+    /// already present in the store.
+    pub bytes_deduplicated: u64,
+
+    /// Number of distinct blocks actually written to the store.
+    pub unique_blocks_written: u64,
+}
+
+/// Trait for hashing-stage implementations.
+///
+/// Hashers take the metadata produced by the analysis stage together with
+/// the extraction directory it describes, and return metadata with content
+/// hashes filled in. This is the last stage to see the extraction
+/// directory: `extraction` is owned here, so it is dropped (and, per
+/// [`TempExtraction::cleanup_on_drop`](crate::harvest::pipeline::TempExtraction),
+/// potentially removed from disk) when `execute` returns.
+pub trait Hasher: Send + Sync {
+    /// Executes the hashing stage.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a file referenced by `metadata` cannot be read
+    /// back from the extraction directory, or if writing to the backing
+    /// store fails.
+    fn execute(
+        &self,
+        metadata: HarvestMetadata,
+        extraction: TempExtraction,
+    ) -> Result<HashOutcome, Box<dyn Error + Send + Sync>>;
+
+    /// Returns the name of this hasher stage.
+    fn stage_name(&self) -> &'static str;
+}
+
+/// Default hasher used when a pipeline is built without
+/// [`with_hasher`](crate::harvest::pipeline::HarvestPipeline::with_hasher).
+///
+/// Leaves every file's `hash` as `None`, preserving pre-CAS behavior.
+pub struct NoopHasher;
+
+impl Hasher for NoopHasher {
+    fn execute(
+        &self,
+        metadata: HarvestMetadata,
+        _extraction: TempExtraction,
+    ) -> Result<HashOutcome, Box<dyn Error + Send + Sync>> {
+        Ok(HashOutcome {
+            metadata,
+            bytes_deduplicated: 0,
+            unique_blocks_written: 0,
+        })
+    }
+
+    fn stage_name(&self) -> &'static str {
+        "noop_hasher"
+    }
+}
+
+/// Content-addressable hasher backed by a [`ContentStore`].
+///
+/// For every [`FileDescriptor`](crate::harvest::traits::FileDescriptor) in
+/// the incoming metadata (symlinks excepted, since they have no body of
+/// their own), reads the file from the extraction directory, writes it into
+/// the store under its multihash address, and records that address as the
+/// file's `hash`.
+pub struct CasHasher {
+    store: Arc<ContentStore>,
+}
+
+impl CasHasher {
+    /// Creates a hasher that writes blocks into `store`.
+    pub fn new(store: Arc<ContentStore>) -> Self {
+        Self { store }
+    }
+}
+
+impl Hasher for CasHasher {
+    fn execute(
+        &self,
+        mut metadata: HarvestMetadata,
+        extraction: TempExtraction,
+    ) -> Result<HashOutcome, Box<dyn Error + Send + Sync>> {
+        let mut bytes_deduplicated = 0u64;
+        let mut unique_blocks_written = 0u64;
+
+        for file in &mut metadata.files {
+            if file.symlink_target.is_some() {
+                continue;
+            }
+
+            let path = extraction.safe_child(&file.path)?;
+            let bytes = fs::read(&path)?;
+            let outcome = self.store.put(&bytes)?;
+
+            if outcome.deduplicated {
+                bytes_deduplicated += bytes.len() as u64;
+            } else {
+                unique_blocks_written += 1;
+            }
+
+            file.hash = Some(outcome.address);
+        }
+
+        Ok(HashOutcome {
+            metadata,
+            bytes_deduplicated,
+            unique_blocks_written,
+        })
+    }
+
+    fn stage_name(&self) -> &'static str {
+        "cas_hasher"
+    }
+}
@@ -0,0 +1,213 @@
+//! Bridges the top-level ecosystem-parser system into the harvest pipeline.
+//!
+//! [`ManifestAnalyzer`] is an [`Analyzer`](crate::harvest::pipeline::Analyzer)
+//! that looks for a known manifest file (`Cargo.toml`, `package.json`, ...)
+//! inside a [`TempExtraction`], hands its bytes to a [`ParserRegistry`], and
+//! folds the resulting [`HarvesterBatch`] into [`HarvestMetadata`] — so a new
+//! ecosystem only needs a [`ParserRegistry::register`] call, not a change
+//! here.
+
+use crate::harvest::diagnostics::{Diagnostic, DiagnosticSpan};
+use crate::harvest::pipeline::{Analyzer, TempExtraction};
+use crate::harvest::traits::{
+    AnalysisError, Dependency, DependencyKind, FileDescriptor, HarvestMetadata,
+};
+use crate::model::HarvesterBatch;
+use crate::registry::ParserRegistry;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Manifest filenames this analyzer looks for, in priority order — the
+/// first one present at the extraction root is the one parsed.
+const CANDIDATE_MANIFESTS: &[&str] = &[
+    "Cargo.toml",
+    "package.json",
+    "requirements.txt",
+    "pyproject.toml",
+    "go.mod",
+    "composer.json",
+    "Gemfile",
+];
+
+/// [`Analyzer`] that turns an extracted package manifest into
+/// [`HarvestMetadata`] via a [`ParserRegistry`].
+pub struct ManifestAnalyzer {
+    registry: ParserRegistry,
+}
+
+impl ManifestAnalyzer {
+    /// Creates an analyzer that dispatches through `registry`.
+    pub fn new(registry: ParserRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl Analyzer for ManifestAnalyzer {
+    fn execute(
+        &self,
+        input: TempExtraction,
+    ) -> Result<(TempExtraction, HarvestMetadata), Box<dyn Error + Send + Sync>> {
+        let manifest_name = CANDIDATE_MANIFESTS
+            .iter()
+            .copied()
+            .find(|name| input.path.join(name).is_file())
+            .ok_or_else(|| {
+                AnalysisError::MissingMetadata(
+                    "no recognized manifest file found in extraction directory".to_string(),
+                )
+            })?;
+
+        let manifest_path = input.safe_child(Path::new(manifest_name))?;
+        let content = fs::read(&manifest_path)?;
+
+        // `Analyzer::execute` is synchronous (it runs inside `spawn_blocking`
+        // on the pipeline side), but `ParserRegistry::parse_auto` is async
+        // like the `EcosystemParser`s it dispatches to — block on it here,
+        // which is sound precisely because we're already on a blocking-pool
+        // thread.
+        let batch = tokio::runtime::Handle::current()
+            .block_on(self.registry.parse_auto(manifest_name, &content))?;
+
+        let metadata = metadata_from_batch(manifest_name, batch)?;
+
+        Ok((input, metadata))
+    }
+
+    fn stage_name(&self) -> &'static str {
+        "manifest_analyzer"
+    }
+}
+
+/// Folds a parsed [`HarvesterBatch`] into [`HarvestMetadata`], keeping the
+/// full batch (vulnerabilities, VCS ref, every node) under `extra` so nothing
+/// the ecosystem parser found is lost to the narrower [`HarvestMetadata`]
+/// shape. A dependency line with an unparseable version constraint is kept
+/// (rather than dropped or failing the analysis) and reported as a
+/// [`Diagnostic::warning`].
+fn metadata_from_batch(
+    manifest_name: &str,
+    batch: HarvesterBatch,
+) -> Result<HarvestMetadata, AnalysisError> {
+    let primary = batch.nodes.first().ok_or_else(|| {
+        AnalysisError::MissingMetadata(format!("{manifest_name} parsed to zero package nodes"))
+    })?;
+
+    let mut diagnostics = Vec::new();
+    let dependencies = primary
+        .dependencies
+        .iter()
+        .map(|dep| {
+            // `LocalPackageNode::dependencies` entries are `"name req"`
+            // (e.g. `"serde ^1.0"`), with the requirement omitted when
+            // unknown.
+            let mut parts = dep.splitn(2, ' ');
+            let name = parts.next().unwrap_or(dep).to_string();
+            let version_constraint = parts.next().map(|req| req.to_string());
+            let dependency = Dependency::new(
+                name.clone(),
+                version_constraint.clone(),
+                DependencyKind::Runtime,
+            );
+
+            if version_constraint.is_some() && dependency.version_req.is_none() {
+                diagnostics.push(
+                    Diagnostic::warning(format!(
+                        "dependency '{name}' has an unparseable version constraint: {}",
+                        version_constraint.as_deref().unwrap_or_default()
+                    ))
+                    .with_span(DiagnosticSpan::new(manifest_name))
+                    .with_help("expected a semver requirement, e.g. \"^1.0\""),
+                );
+            }
+
+            dependency
+        })
+        .collect();
+
+    let source_format = primary.ecosystem.clone();
+    let package_name = primary.name.clone();
+    let version = primary.version.clone();
+
+    let harvest_timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+
+    let mut extra = HashMap::new();
+    extra.insert(
+        "ecosystem_batch".to_string(),
+        serde_json::to_value(&batch).map_err(|e| AnalysisError::JsonParsing(e.to_string()))?,
+    );
+
+    Ok(HarvestMetadata {
+        source_format,
+        package_name,
+        version,
+        dependencies,
+        files: vec![FileDescriptor {
+            path: manifest_name.into(),
+            hash: None,
+            size: 0,
+            permissions: 0o644,
+            symlink_target: None,
+        }],
+        capabilities: vec![],
+        harvest_timestamp,
+        harvester_version: env!("CARGO_PKG_VERSION").to_string(),
+        diagnostics,
+        extra,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::harvest::diagnostics::Severity;
+    use crate::model::LocalPackageNode;
+
+    fn node(deps: &[&str]) -> LocalPackageNode {
+        LocalPackageNode {
+            name: "demo".to_string(),
+            version: "1.0.0".to_string(),
+            ecosystem: "cargo".to_string(),
+            description: None,
+            license: None,
+            dependencies: deps.iter().map(|d| d.to_string()).collect(),
+            dependency_kinds: HashMap::new(),
+            dependency_targets: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_metadata_from_batch_warns_on_unparseable_constraint() {
+        let batch = HarvesterBatch {
+            nodes: vec![node(&["serde not-a-semver-range"])],
+            vulnerabilities: vec![],
+            source_vcs: None,
+        };
+
+        let metadata = metadata_from_batch("Cargo.toml", batch).unwrap();
+
+        assert_eq!(metadata.diagnostics.len(), 1);
+        assert_eq!(metadata.diagnostics[0].severity, Severity::Warning);
+        assert_eq!(
+            metadata.diagnostics[0].spans[0].file,
+            Path::new("Cargo.toml")
+        );
+    }
+
+    #[test]
+    fn test_metadata_from_batch_has_no_diagnostics_for_valid_constraints() {
+        let batch = HarvesterBatch {
+            nodes: vec![node(&["serde ^1.0"])],
+            vulnerabilities: vec![],
+            source_vcs: None,
+        };
+
+        let metadata = metadata_from_batch("Cargo.toml", batch).unwrap();
+        assert!(metadata.diagnostics.is_empty());
+    }
+}
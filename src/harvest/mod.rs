@@ -5,8 +5,16 @@
 //! - **Metadata**: Normalized package information via [`HarvestMetadata`]
 //! - **Errors**: Standardized error types for each pipeline stage
 //! - **Pipeline**: Async executor via [`pipeline::HarvestPipeline`]
+//! - **CAS**: Content-addressed block storage via [`cas::ContentStore`]
 
+pub mod cache;
+pub mod cas;
+pub mod diagnostics;
+pub mod hasher;
+pub mod manifest;
 pub mod pipeline;
+pub mod platform;
+pub mod reporter;
 pub mod traits;
 
 // Re-export commonly used types
@@ -15,7 +23,16 @@ pub use traits::{
     HarvestStage, PackageFormat, ValidationError, ValidationReport,
 };
 
+pub use diagnostics::{to_json_lines, Diagnostic, DiagnosticSpan, Severity};
+pub use platform::{CfgExpr, CfgParseError, Platform, TargetCfg};
+
 pub use pipeline::{
-    Analyzer, Extractor, HarvestPipeline, HarvestResult, HarvestStats, PipelineError, SourceInfo,
-    TempExtraction,
+    Analyzer, BatchResult, Extractor, HarvestEvent, HarvestPipeline, HarvestResult, HarvestStats,
+    PipelineError, SourceInfo, TempExtraction,
 };
+
+pub use cache::{compute_cache_key, HarvestCache};
+pub use cas::ContentStore;
+pub use hasher::{CasHasher, HashOutcome, Hasher, NoopHasher};
+pub use manifest::ManifestAnalyzer;
+pub use reporter::{JUnitReporter, NoopReporter, PrettyReporter, Reporter};
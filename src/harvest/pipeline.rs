@@ -1,17 +1,31 @@
 //! Modular harvest pipeline executor.
 //!
 //! This module provides the [`HarvestPipeline`] coordinator that executes
-//! sequential harvest stages (Extractor → Analyzer → Hasher → Indexer) with:
+//! sequential harvest stages (Extractor → Analyzer → Hasher) with:
 //! - Async execution via `tokio`
 //! - Configurable timeouts per stage
 //! - Structured logging via `tracing`
 //! - Automatic cleanup of temporary resources via RAII (`Drop` on `TempExtraction`)
 
+use futures::stream::{self, StreamExt};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
-use std::time::Duration;
-use tokio::time::timeout;
+use std::sync::mpsc as std_mpsc;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc as tokio_mpsc;
+use tokio::task::JoinHandle;
+use tokio::time::{interval, timeout, MissedTickBehavior};
+use tokio_util::sync::CancellationToken;
 use tracing::{info, warn};
 
+use crate::harvest::cache::{compute_cache_key, HarvestCache};
+use crate::harvest::hasher::{HashOutcome, Hasher, NoopHasher};
+use crate::harvest::reporter::{NoopReporter, Reporter};
 use crate::harvest::traits::HarvestMetadata;
 
 // ============================================================================
@@ -92,6 +106,18 @@ impl TempExtraction {
             }
         }
 
+        // The component check alone doesn't catch a symlink (e.g. a
+        // malicious package placing a symlinked directory inside the
+        // extraction root) that resolves outside it. If `candidate` exists,
+        // confirm its canonical path is still contained in `root`.
+        if let Ok(canonical) = candidate.canonicalize() {
+            if !canonical.starts_with(&root) {
+                return Err(PipelineError::PathTraversal {
+                    attempted: relative.display().to_string(),
+                });
+            }
+        }
+
         Ok(candidate)
     }
 }
@@ -153,11 +179,66 @@ pub struct HarvestStats {
     /// Time spent on analysis stage (milliseconds)
     pub analysis_duration_ms: u64,
 
+    /// Time spent on hashing stage (milliseconds)
+    pub hashing_duration_ms: u64,
+
     /// Number of files processed
     pub files_processed: usize,
 
     /// Total size of all files (bytes)
     pub total_size_bytes: u64,
+
+    /// Bytes not written to the CAS store because an identical block was
+    /// already present (cross-package deduplication).
+    pub bytes_deduplicated: u64,
+
+    /// Number of distinct blocks actually written to the CAS store.
+    pub unique_blocks_written: u64,
+
+    /// `true` if this result came from the incremental cache, skipping
+    /// extraction, analysis, and hashing entirely.
+    pub cache_hit: bool,
+}
+
+/// Aggregated result of [`HarvestPipeline::harvest_batch`].
+#[derive(Debug)]
+pub struct BatchResult {
+    /// Per-package outcomes, one per input source. Order matches completion
+    /// order (via `buffer_unordered`), not necessarily input order.
+    pub results: Vec<Result<HarvestResult, PipelineError>>,
+
+    /// Statistics summed across every successful package in the batch.
+    pub stats: HarvestStats,
+
+    /// Seed used to shuffle dispatch order, if [`with_shuffle_seed`](HarvestPipeline::with_shuffle_seed)
+    /// was set.
+    pub shuffle_seed: Option<u64>,
+}
+
+/// Progress event emitted by [`HarvestPipeline::execute_with_events`] as a
+/// harvest runs, so a UI/CLI can render live throughput instead of waiting
+/// for the final [`HarvestResult`].
+#[derive(Debug, Clone)]
+pub enum HarvestEvent {
+    /// Extraction stage has begun.
+    ExtractionStarted,
+
+    /// Extraction stage finished successfully.
+    ExtractionFinished { duration_ms: u64, path: PathBuf },
+
+    /// Analysis stage has begun.
+    AnalysisStarted,
+
+    /// One file has passed through the hashing stage; sent for every file in
+    /// the metadata once that stage completes, including symlinks and files
+    /// a [`NoopHasher`] left unhashed.
+    FileProcessed { path: PathBuf, size: u64 },
+
+    /// A stage exceeded [`with_timeout`](HarvestPipeline::with_timeout).
+    StageTimeout { stage: String, timeout_secs: u64 },
+
+    /// The harvest finished successfully.
+    Completed(HarvestStats),
 }
 
 // ============================================================================
@@ -179,6 +260,14 @@ pub enum PipelineError {
     #[error("Analysis failed: {0}")]
     AnalysisFailed(String),
 
+    /// Hashing stage failed
+    #[error("Hashing failed: {0}")]
+    HashingFailed(String),
+
+    /// Filesystem watch setup or teardown failed
+    #[error("Watch failed: {0}")]
+    WatchFailed(String),
+
     /// A path inside the package would escape the extraction root (path traversal)
     #[error("Path traversal attempt rejected: '{attempted}'")]
     PathTraversal { attempted: String },
@@ -186,6 +275,11 @@ pub enum PipelineError {
     /// Generic I/O error
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
+
+    /// Harvest was aborted via the [`CancellationToken`] returned by
+    /// [`HarvestPipeline::execute_with_events`].
+    #[error("Harvest cancelled")]
+    Cancelled,
 }
 
 // ============================================================================
@@ -197,7 +291,7 @@ pub enum PipelineError {
 /// The pipeline coordinates sequential execution of harvest stages:
 /// 1. **Extraction**: Unpack package to temporary directory
 /// 2. **Analysis**: Parse metadata and generate [`HarvestMetadata`]
-/// 3. (Future) **Hashing**: Compute content hashes for CAS
+/// 3. **Hashing**: Content-address each file into a CAS store via [`Hasher`]
 /// 4. (Future) **Indexing**: Store metadata in searchable index
 ///
 /// # Thread Safety
@@ -222,22 +316,57 @@ pub enum PipelineError {
 ///     Ok(())
 /// }
 /// ```
-pub struct HarvestPipeline<E, A>
+pub struct HarvestPipeline<E, A, H = NoopHasher, R = NoopReporter>
 where
     E: Extractor,
     A: Analyzer,
+    H: Hasher,
+    R: Reporter,
 {
-    /// Extraction stage implementation
-    extractor: E,
+    /// Extraction stage implementation. `Arc`-wrapped so each stage's
+    /// blocking call can move an owned handle into `spawn_blocking` instead
+    /// of borrowing `&self` across an `'static` boundary.
+    extractor: Arc<E>,
+
+    /// Analysis stage implementation. `Arc`-wrapped for the same reason as
+    /// `extractor`.
+    analyzer: Arc<A>,
+
+    /// Hashing stage implementation (defaults to [`NoopHasher`]). `Arc`-wrapped
+    /// for the same reason as `extractor`.
+    hasher: Arc<H>,
 
-    /// Analysis stage implementation
-    analyzer: A,
+    /// Progress/result observer (defaults to [`NoopReporter`])
+    reporter: R,
 
     /// Timeout for each stage (default: 5 minutes)
     stage_timeout: Duration,
 
     /// Whether to automatically cleanup temporary directories
     auto_cleanup: bool,
+
+    /// Maximum number of packages processed concurrently by [`harvest_batch`](Self::harvest_batch)
+    concurrency: usize,
+
+    /// Optional seed to shuffle batch input order deterministically before
+    /// dispatch, so ordering-dependent bugs surface reproducibly
+    shuffle_seed: Option<u64>,
+
+    /// How long a watched file must go without further writes before
+    /// [`watch`](Self::watch) dispatches it (default: 200ms)
+    quiet_window: Duration,
+
+    /// Incremental cache consulted before extraction (default: disabled)
+    cache: Option<Arc<HarvestCache>>,
+}
+
+/// Per-path debounce state tracked by [`HarvestPipeline::watch`].
+struct PendingFile {
+    /// When this path last had a create/modify event or a size change.
+    last_seen: Instant,
+
+    /// File size as of the last time we checked it.
+    last_size: u64,
 }
 
 /// Trait for extraction stage implementations.
@@ -272,9 +401,11 @@ pub trait Extractor: Send + Sync {
 ///
 /// # Note on `TempExtraction` ownership
 ///
-/// The analyzer receives ownership of `TempExtraction`. When the analyzer
-/// returns (success or error), `Drop` runs and cleans up the temp directory
-/// automatically if `cleanup_on_drop` is set.
+/// The analyzer receives ownership of `TempExtraction` and hands it back
+/// alongside the metadata it produced, so the hashing stage that follows
+/// can still read file bodies out of the same extraction directory. `Drop`
+/// only runs — cleaning up the temp directory if `cleanup_on_drop` is set —
+/// once the last stage to receive it (currently [`Hasher`]) is done with it.
 pub trait Analyzer: Send + Sync {
     /// Analyzes extracted package and generates metadata.
     ///
@@ -284,13 +415,13 @@ pub trait Analyzer: Send + Sync {
     fn execute(
         &self,
         input: TempExtraction,
-    ) -> Result<HarvestMetadata, Box<dyn std::error::Error + Send + Sync>>;
+    ) -> Result<(TempExtraction, HarvestMetadata), Box<dyn std::error::Error + Send + Sync>>;
 
     /// Returns the name of this analyzer stage.
     fn stage_name(&self) -> &'static str;
 }
 
-impl<E, A> HarvestPipeline<E, A>
+impl<E, A> HarvestPipeline<E, A, NoopHasher, NoopReporter>
 where
     E: Extractor,
     A: Analyzer,
@@ -300,15 +431,105 @@ where
     /// Default configuration:
     /// - Timeout: 5 minutes per stage
     /// - Auto-cleanup: enabled
+    /// - Hasher: [`NoopHasher`] (every file's `hash` stays `None`, as before
+    ///   the CAS hashing stage existed) — call [`with_hasher`](Self::with_hasher)
+    ///   to enable content addressing.
+    /// - Reporter: [`NoopReporter`] — call [`with_reporter`](Self::with_reporter)
+    ///   to observe progress.
     pub fn new(extractor: E, analyzer: A) -> Self {
         Self {
-            extractor,
-            analyzer,
+            extractor: Arc::new(extractor),
+            analyzer: Arc::new(analyzer),
+            hasher: Arc::new(NoopHasher),
+            reporter: NoopReporter,
             stage_timeout: Duration::from_secs(300), // 5 minutes
             auto_cleanup: true,
+            concurrency: 4,
+            shuffle_seed: None,
+            quiet_window: Duration::from_millis(200),
+            cache: None,
+        }
+    }
+}
+
+impl<E, A, H, R> HarvestPipeline<E, A, H, R>
+where
+    E: Extractor + 'static,
+    A: Analyzer + 'static,
+    H: Hasher + 'static,
+    R: Reporter,
+{
+    /// Replaces the hashing stage, e.g. with a [`CasHasher`](crate::harvest::hasher::CasHasher)
+    /// to populate content hashes and deduplicate file bodies into a CAS store.
+    pub fn with_hasher<H2: Hasher>(self, hasher: H2) -> HarvestPipeline<E, A, H2, R> {
+        HarvestPipeline {
+            extractor: self.extractor,
+            analyzer: self.analyzer,
+            hasher: Arc::new(hasher),
+            reporter: self.reporter,
+            stage_timeout: self.stage_timeout,
+            auto_cleanup: self.auto_cleanup,
+            concurrency: self.concurrency,
+            shuffle_seed: self.shuffle_seed,
+            quiet_window: self.quiet_window,
+            cache: self.cache.clone(),
         }
     }
 
+    /// Replaces the progress reporter, e.g. with a [`PrettyReporter`](crate::harvest::reporter::PrettyReporter)
+    /// or [`JUnitReporter`](crate::harvest::reporter::JUnitReporter).
+    pub fn with_reporter<R2: Reporter>(self, reporter: R2) -> HarvestPipeline<E, A, H, R2> {
+        HarvestPipeline {
+            extractor: self.extractor,
+            analyzer: self.analyzer,
+            hasher: self.hasher,
+            reporter,
+            stage_timeout: self.stage_timeout,
+            auto_cleanup: self.auto_cleanup,
+            concurrency: self.concurrency,
+            shuffle_seed: self.shuffle_seed,
+            quiet_window: self.quiet_window,
+            cache: self.cache.clone(),
+        }
+    }
+
+    /// Sets the maximum number of packages [`harvest_batch`](Self::harvest_batch)
+    /// processes concurrently (default: 4).
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Seeds a deterministic shuffle of [`harvest_batch`](Self::harvest_batch)'s
+    /// input order before dispatch, so ordering-dependent bugs surface
+    /// reproducibly. `None` (the default) dispatches sources in the order given.
+    pub fn with_shuffle_seed(mut self, seed: u64) -> Self {
+        self.shuffle_seed = Some(seed);
+        self
+    }
+
+    /// Sets how long a file must go without further writes before
+    /// [`watch`](Self::watch) considers it stable and dispatches it
+    /// (default: 200ms).
+    pub fn with_quiet_window(mut self, quiet_window: Duration) -> Self {
+        self.quiet_window = quiet_window;
+        self
+    }
+
+    /// Enables the incremental cache: before extraction, [`execute`](Self::execute)
+    /// hashes the source file and checks `path` for a prior
+    /// [`HarvestMetadata`] tagged with the current `harvester_version`. A
+    /// hit skips extraction, analysis, and hashing entirely; bumping the
+    /// crate version invalidates every existing entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `path` cannot be created or accessed.
+    pub fn with_cache(mut self, path: impl Into<PathBuf>) -> std::io::Result<Self> {
+        self.cache = Some(Arc::new(HarvestCache::open(path)?));
+        Ok(self)
+    }
+
     /// Sets the timeout for each pipeline stage.
     ///
     /// # Arguments
@@ -334,7 +555,8 @@ where
     /// This method orchestrates all pipeline stages sequentially:
     /// 1. Extraction with timeout
     /// 2. Analysis with timeout
-    /// 3. Cleanup (if enabled)
+    /// 3. Hashing with timeout
+    /// 4. Cleanup (if enabled)
     ///
     /// # Arguments
     ///
@@ -346,6 +568,7 @@ where
     /// - Any stage times out
     /// - Extraction fails (unsupported format, I/O error)
     /// - Analysis fails (missing metadata, parsing error)
+    /// - Hashing fails (file went missing, store write error)
     ///
     /// # Performance
     ///
@@ -355,6 +578,50 @@ where
         let start = std::time::Instant::now();
         let mut stats = HarvestStats::default();
 
+        self.reporter.on_run_start();
+        self.reporter.on_package_start(&source);
+
+        // ====================================================================
+        // Incremental cache lookup
+        // ====================================================================
+        //
+        // Hashing the source file up front lets an unchanged package skip
+        // both blocking stages entirely. A failure here (e.g. source file
+        // vanished) just falls through to a normal extraction, which will
+        // report the real error with better context.
+
+        if let Some(cache) = self.cache.clone() {
+            let key_source = source.clone();
+            let lookup = tokio::task::spawn_blocking(move || {
+                let key = compute_cache_key(&key_source)?;
+                Ok::<_, std::io::Error>(cache.get(&key, env!("CARGO_PKG_VERSION")))
+            })
+            .await;
+
+            if let Ok(Ok(Some(metadata))) = lookup {
+                let mut stats = HarvestStats {
+                    cache_hit: true,
+                    files_processed: metadata.files.len(),
+                    total_size_bytes: metadata.files.iter().map(|f| f.size).sum(),
+                    ..Default::default()
+                };
+                stats.total_duration_ms = start.elapsed().as_millis() as u64;
+
+                info!(package = %metadata.package_name, "Cache hit — skipping extraction, analysis, and hashing");
+
+                let result = HarvestResult {
+                    metadata,
+                    extraction_path: None,
+                    stats,
+                };
+
+                self.reporter.on_package_complete(&result);
+                self.reporter.on_run_end();
+
+                return Ok(result);
+            }
+        }
+
         // ====================================================================
         // Stage 1: Extraction
         // ====================================================================
@@ -362,31 +629,50 @@ where
         info!("Starting extraction stage");
         let extraction_start = std::time::Instant::now();
 
-        let extractor = &self.extractor;
+        let extractor = Arc::clone(&self.extractor);
         let source_clone = source.clone();
         let auto_cleanup = self.auto_cleanup;
 
-        let mut temp = timeout(self.stage_timeout, async move {
-            tokio::task::spawn_blocking(move || extractor.execute(source_clone, auto_cleanup)).await
-        })
-        .await
-        .map_err(|_| PipelineError::StageTimeout {
-            stage: self.extractor.stage_name().to_string(),
-            timeout_secs: self.stage_timeout.as_secs(),
-        })?
-        .map_err(|e| PipelineError::ExtractionFailed(format!("Task join error: {}", e)))?
-        .map_err(|e| PipelineError::ExtractionFailed(e.to_string()))?;
+        let extraction_result: Result<TempExtraction, PipelineError> = async {
+            timeout(self.stage_timeout, async move {
+                tokio::task::spawn_blocking(move || extractor.execute(source_clone, auto_cleanup))
+                    .await
+            })
+            .await
+            .map_err(|_| PipelineError::StageTimeout {
+                stage: self.extractor.stage_name().to_string(),
+                timeout_secs: self.stage_timeout.as_secs(),
+            })?
+            .map_err(|e| PipelineError::ExtractionFailed(format!("Task join error: {}", e)))?
+            .map_err(|e| PipelineError::ExtractionFailed(e.to_string()))
+        }
+        .await;
+
+        let mut temp = match extraction_result {
+            Ok(temp) => {
+                stats.extraction_duration_ms = extraction_start.elapsed().as_millis() as u64;
+                info!(
+                    duration_ms = stats.extraction_duration_ms,
+                    path = %temp.path.display(),
+                    "Extraction completed"
+                );
+                self.reporter
+                    .on_stage_complete(self.extractor.stage_name(), stats.extraction_duration_ms);
+                temp
+            }
+            Err(e) => {
+                let duration_ms = extraction_start.elapsed().as_millis() as u64;
+                self.reporter
+                    .on_stage_failed(self.extractor.stage_name(), &e, duration_ms);
+                self.reporter.on_package_failed(&source, &e);
+                self.reporter.on_run_end();
+                return Err(e);
+            }
+        };
 
         // Propagate auto_cleanup flag so Drop handles cleanup on any error path.
         temp.cleanup_on_drop = auto_cleanup;
 
-        stats.extraction_duration_ms = extraction_start.elapsed().as_millis() as u64;
-        info!(
-            duration_ms = stats.extraction_duration_ms,
-            path = %temp.path.display(),
-            "Extraction completed"
-        );
-
         // ====================================================================
         // Stage 2: Analysis
         // ====================================================================
@@ -398,7 +684,7 @@ where
         info!("Starting analysis stage");
         let analysis_start = std::time::Instant::now();
 
-        let analyzer = &self.analyzer;
+        let analyzer = Arc::clone(&self.analyzer);
         // Capture the extraction path BEFORE moving temp into the task.
         // We need it for HarvestResult regardless of cleanup setting.
         let extraction_path_for_result = if auto_cleanup {
@@ -407,38 +693,612 @@ where
             Some(temp.path.clone())
         };
 
-        let metadata = timeout(self.stage_timeout, async move {
-            // `temp` is moved here. On success or failure, Drop runs cleanup.
-            tokio::task::spawn_blocking(move || analyzer.execute(temp)).await
-        })
-        .await
-        .map_err(|_| PipelineError::StageTimeout {
-            stage: self.analyzer.stage_name().to_string(),
-            timeout_secs: self.stage_timeout.as_secs(),
-        })?
-        .map_err(|e| PipelineError::AnalysisFailed(format!("Task join error: {}", e)))?
-        .map_err(|e| PipelineError::AnalysisFailed(e.to_string()))?;
-
-        stats.analysis_duration_ms = analysis_start.elapsed().as_millis() as u64;
+        let analysis_result: Result<(TempExtraction, HarvestMetadata), PipelineError> = async {
+            timeout(self.stage_timeout, async move {
+                // `temp` is moved here and handed back on success so the hashing
+                // stage below can still read files out of it. On failure it is
+                // dropped inside the task, triggering cleanup via Drop.
+                tokio::task::spawn_blocking(move || analyzer.execute(temp)).await
+            })
+            .await
+            .map_err(|_| PipelineError::StageTimeout {
+                stage: self.analyzer.stage_name().to_string(),
+                timeout_secs: self.stage_timeout.as_secs(),
+            })?
+            .map_err(|e| PipelineError::AnalysisFailed(format!("Task join error: {}", e)))?
+            .map_err(|e| PipelineError::AnalysisFailed(e.to_string()))
+        }
+        .await;
+
+        let (temp, metadata) = match analysis_result {
+            Ok((temp, metadata)) => {
+                stats.analysis_duration_ms = analysis_start.elapsed().as_millis() as u64;
+                info!(
+                    duration_ms = stats.analysis_duration_ms,
+                    files = metadata.files.len(),
+                    "Analysis completed"
+                );
+                self.reporter
+                    .on_stage_complete(self.analyzer.stage_name(), stats.analysis_duration_ms);
+                (temp, metadata)
+            }
+            Err(e) => {
+                let duration_ms = analysis_start.elapsed().as_millis() as u64;
+                self.reporter
+                    .on_stage_failed(self.analyzer.stage_name(), &e, duration_ms);
+                self.reporter.on_package_failed(&source, &e);
+                self.reporter.on_run_end();
+                return Err(e);
+            }
+        };
+
+        // ====================================================================
+        // Stage 3: Hashing
+        // ====================================================================
+        //
+        // `temp` is moved into the blocking task below; it is the last stage
+        // to hold it, so Drop (and any cleanup) runs when this task ends.
+
+        info!("Starting hashing stage");
+        let hashing_start = std::time::Instant::now();
+
+        let hasher = Arc::clone(&self.hasher);
+
+        let hashing_result: Result<HashOutcome, PipelineError> = async {
+            timeout(self.stage_timeout, async move {
+                tokio::task::spawn_blocking(move || hasher.execute(metadata, temp)).await
+            })
+            .await
+            .map_err(|_| PipelineError::StageTimeout {
+                stage: self.hasher.stage_name().to_string(),
+                timeout_secs: self.stage_timeout.as_secs(),
+            })?
+            .map_err(|e| PipelineError::HashingFailed(format!("Task join error: {}", e)))?
+            .map_err(|e| PipelineError::HashingFailed(e.to_string()))
+        }
+        .await;
+
+        let hash_outcome = match hashing_result {
+            Ok(outcome) => {
+                stats.hashing_duration_ms = hashing_start.elapsed().as_millis() as u64;
+                self.reporter
+                    .on_stage_complete(self.hasher.stage_name(), stats.hashing_duration_ms);
+                outcome
+            }
+            Err(e) => {
+                let duration_ms = hashing_start.elapsed().as_millis() as u64;
+                self.reporter
+                    .on_stage_failed(self.hasher.stage_name(), &e, duration_ms);
+                self.reporter.on_package_failed(&source, &e);
+                self.reporter.on_run_end();
+                return Err(e);
+            }
+        };
+
+        stats.bytes_deduplicated = hash_outcome.bytes_deduplicated;
+        stats.unique_blocks_written = hash_outcome.unique_blocks_written;
+
+        let metadata = hash_outcome.metadata;
         stats.files_processed = metadata.files.len();
         stats.total_size_bytes = metadata.files.iter().map(|f| f.size).sum();
 
         info!(
-            duration_ms = stats.analysis_duration_ms,
-            files = stats.files_processed,
-            size_bytes = stats.total_size_bytes,
-            "Analysis completed"
+            duration_ms = stats.hashing_duration_ms,
+            unique_blocks_written = stats.unique_blocks_written,
+            bytes_deduplicated = stats.bytes_deduplicated,
+            "Hashing completed"
         );
 
         stats.total_duration_ms = start.elapsed().as_millis() as u64;
 
         // No explicit cleanup needed here — Drop on TempExtraction handles it.
 
-        Ok(HarvestResult {
+        if let Some(cache) = self.cache.clone() {
+            let put_source = source.clone();
+            let put_metadata = metadata.clone();
+            let put_result = tokio::task::spawn_blocking(move || {
+                let key = compute_cache_key(&put_source)?;
+                cache.put(&key, &put_metadata)
+            })
+            .await;
+
+            match put_result {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => warn!(error = %e, "Failed to write incremental cache entry"),
+                Err(e) => warn!(error = %e, "Cache write task panicked"),
+            }
+        }
+
+        let result = HarvestResult {
             metadata,
             extraction_path: extraction_path_for_result,
             stats,
-        })
+        };
+
+        self.reporter.on_package_complete(&result);
+        self.reporter.on_run_end();
+
+        Ok(result)
+    }
+
+    /// Harvests many packages concurrently, bounded by [`with_concurrency`](Self::with_concurrency).
+    ///
+    /// Runs [`execute`](Self::execute) over `sources` using a bounded worker
+    /// pool (`buffer_unordered`), so a single slow or timed-out package can't
+    /// stall or abort the rest of the batch — every per-package outcome
+    /// (success or [`PipelineError`]) is collected rather than propagated.
+    ///
+    /// If [`with_shuffle_seed`](Self::with_shuffle_seed) was set, `sources`
+    /// is shuffled with a seeded RNG before dispatch, so ordering-dependent
+    /// bugs surface reproducibly; the seed used is recorded on the returned
+    /// [`BatchResult`].
+    pub async fn harvest_batch(&self, mut sources: Vec<PathBuf>) -> BatchResult {
+        if let Some(seed) = self.shuffle_seed {
+            let mut rng = StdRng::seed_from_u64(seed);
+            sources.shuffle(&mut rng);
+        }
+
+        let concurrency = self.concurrency.max(1);
+
+        let results: Vec<Result<HarvestResult, PipelineError>> = stream::iter(sources)
+            .map(|source| self.execute(source))
+            .buffer_unordered(concurrency)
+            .collect()
+            .await;
+
+        let mut stats = HarvestStats::default();
+        for result in results.iter().flatten() {
+            stats.total_duration_ms += result.stats.total_duration_ms;
+            stats.extraction_duration_ms += result.stats.extraction_duration_ms;
+            stats.analysis_duration_ms += result.stats.analysis_duration_ms;
+            stats.hashing_duration_ms += result.stats.hashing_duration_ms;
+            stats.files_processed += result.stats.files_processed;
+            stats.total_size_bytes += result.stats.total_size_bytes;
+            stats.bytes_deduplicated += result.stats.bytes_deduplicated;
+            stats.unique_blocks_written += result.stats.unique_blocks_written;
+        }
+
+        BatchResult {
+            results,
+            stats,
+            shuffle_seed: self.shuffle_seed,
+        }
+    }
+
+    /// Watches `dir` for newly created or modified package files and
+    /// harvests each one, emitting progress through the configured
+    /// [`Reporter`] as [`execute`](Self::execute) does for any other call.
+    ///
+    /// Events are debounced per-path: a file is only dispatched once it has
+    /// gone [`quiet_window`](Self::with_quiet_window) without a new event
+    /// *and* its size is unchanged between two checks, so a file still being
+    /// copied into the directory isn't harvested mid-write.
+    ///
+    /// Runs until the underlying filesystem watcher's event channel closes
+    /// (normally only on teardown); returns [`PipelineError::WatchFailed`]
+    /// if the watcher itself cannot be created.
+    pub async fn watch(&self, dir: PathBuf) -> Result<(), PipelineError> {
+        let (std_tx, std_rx) = std_mpsc::channel::<notify::Result<Event>>();
+
+        let mut watcher = RecommendedWatcher::new(
+            move |res| {
+                let _ = std_tx.send(res);
+            },
+            notify::Config::default(),
+        )
+        .map_err(|e| PipelineError::WatchFailed(e.to_string()))?;
+
+        watcher
+            .watch(&dir, RecursiveMode::NonRecursive)
+            .map_err(|e| PipelineError::WatchFailed(e.to_string()))?;
+
+        info!(dir = %dir.display(), "Watching directory for new packages");
+
+        // notify's callback is synchronous (and may run on its own thread);
+        // bridge it onto a tokio channel so we can select! against the
+        // debounce ticker below.
+        let (tx, mut rx) = tokio_mpsc::unbounded_channel();
+        let bridge = tokio::task::spawn_blocking(move || {
+            while let Ok(res) = std_rx.recv() {
+                if tx.send(res).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut pending: HashMap<PathBuf, PendingFile> = HashMap::new();
+        let mut tick = interval(self.quiet_window);
+        tick.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                maybe_event = rx.recv() => {
+                    match maybe_event {
+                        Some(Ok(event)) => self.note_event(&mut pending, event),
+                        Some(Err(e)) => warn!(error = %e, "watch event error"),
+                        None => break, // watcher callback channel closed
+                    }
+                }
+                _ = tick.tick() => {
+                    self.dispatch_stable_files(&mut pending).await;
+                }
+            }
+        }
+
+        drop(watcher);
+        let _ = bridge.await;
+
+        Ok(())
+    }
+
+    /// Records a filesystem event against the debounce map, resetting the
+    /// quiet window for every path it touches.
+    fn note_event(&self, pending: &mut HashMap<PathBuf, PendingFile>, event: Event) {
+        if !matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+            return;
+        }
+
+        for path in event.paths {
+            if !path.is_file() {
+                continue;
+            }
+            let size = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+            pending.insert(
+                path,
+                PendingFile {
+                    last_seen: Instant::now(),
+                    last_size: size,
+                },
+            );
+        }
+    }
+
+    /// Dispatches every pending path that has been quiet for at least
+    /// `quiet_window` and whose size hasn't changed since it was last
+    /// checked; paths still growing have their quiet window reset instead.
+    async fn dispatch_stable_files(&self, pending: &mut HashMap<PathBuf, PendingFile>) {
+        let now = Instant::now();
+        let mut ready = Vec::new();
+
+        for (path, state) in pending.iter_mut() {
+            if now.duration_since(state.last_seen) < self.quiet_window {
+                continue;
+            }
+
+            let current_size = std::fs::metadata(path)
+                .map(|m| m.len())
+                .unwrap_or(state.last_size);
+            if current_size != state.last_size {
+                // Still being written — reset the quiet window and keep waiting.
+                state.last_size = current_size;
+                state.last_seen = now;
+                continue;
+            }
+
+            ready.push(path.clone());
+        }
+
+        for path in ready {
+            pending.remove(&path);
+            info!(path = %path.display(), "Dispatching stabilized file");
+            let _ = self.execute(path).await;
+        }
+    }
+}
+
+/// Requires `'static` stages so the harvest can run on a detached
+/// [`tokio::spawn`] task — unlike [`execute`](HarvestPipeline::execute), this
+/// variant doesn't run on the caller's task, so it can keep emitting
+/// [`HarvestEvent`]s while the caller does something else with the result.
+impl<E, A, H, R> HarvestPipeline<E, A, H, R>
+where
+    E: Extractor + 'static,
+    A: Analyzer + 'static,
+    H: Hasher + 'static,
+    R: Reporter + 'static,
+{
+    /// Runs [`execute`](Self::execute) on a detached task, streaming
+    /// [`HarvestEvent`]s as it progresses instead of only returning the final
+    /// [`HarvestResult`].
+    ///
+    /// Returns the task's [`JoinHandle`], a receiver for live progress
+    /// events, and a [`CancellationToken`]. Calling
+    /// [`cancel`](CancellationToken::cancel) on the token aborts the harvest
+    /// at its next stage boundary (or mid-stage, for the stage currently
+    /// running): the in-flight stage is abandoned rather than awaited, and
+    /// `Drop` on [`TempExtraction`] still guarantees its temp directory is
+    /// cleaned up once that abandoned task finishes in the background.
+    ///
+    /// The event channel is dropped (ending the receiver) when the task
+    /// completes, whether it succeeded, failed, or was cancelled — callers
+    /// that only care about the final outcome can simply `.await` the
+    /// `JoinHandle` instead of draining the receiver.
+    pub fn execute_with_events(
+        self: Arc<Self>,
+        source: PathBuf,
+    ) -> (
+        JoinHandle<Result<HarvestResult, PipelineError>>,
+        tokio_mpsc::UnboundedReceiver<HarvestEvent>,
+        CancellationToken,
+    ) {
+        let (tx, rx) = tokio_mpsc::unbounded_channel();
+        let cancel = CancellationToken::new();
+        let task_cancel = cancel.clone();
+
+        let handle =
+            tokio::spawn(async move { self.execute_instrumented(source, tx, task_cancel).await });
+
+        (handle, rx, cancel)
+    }
+
+    /// Event-emitting, cancellable twin of [`execute`](Self::execute).
+    ///
+    /// Mirrors its stage-by-stage structure, but races each stage's timeout
+    /// future against `cancel` and reports progress through `events` instead
+    /// of only a [`Reporter`].
+    async fn execute_instrumented(
+        &self,
+        source: PathBuf,
+        events: tokio_mpsc::UnboundedSender<HarvestEvent>,
+        cancel: CancellationToken,
+    ) -> Result<HarvestResult, PipelineError> {
+        let start = std::time::Instant::now();
+        let mut stats = HarvestStats::default();
+
+        self.reporter.on_run_start();
+        self.reporter.on_package_start(&source);
+
+        // ====================================================================
+        // Incremental cache lookup
+        // ====================================================================
+
+        if let Some(cache) = self.cache.clone() {
+            let key_source = source.clone();
+            let lookup = tokio::task::spawn_blocking(move || {
+                let key = compute_cache_key(&key_source)?;
+                Ok::<_, std::io::Error>(cache.get(&key, env!("CARGO_PKG_VERSION")))
+            })
+            .await;
+
+            if let Ok(Ok(Some(metadata))) = lookup {
+                let mut stats = HarvestStats {
+                    cache_hit: true,
+                    files_processed: metadata.files.len(),
+                    total_size_bytes: metadata.files.iter().map(|f| f.size).sum(),
+                    ..Default::default()
+                };
+                stats.total_duration_ms = start.elapsed().as_millis() as u64;
+
+                let result = HarvestResult {
+                    metadata,
+                    extraction_path: None,
+                    stats: stats.clone(),
+                };
+
+                self.reporter.on_package_complete(&result);
+                self.reporter.on_run_end();
+                let _ = events.send(HarvestEvent::Completed(stats));
+
+                return Ok(result);
+            }
+        }
+
+        // ====================================================================
+        // Stage 1: Extraction
+        // ====================================================================
+
+        let _ = events.send(HarvestEvent::ExtractionStarted);
+        let extraction_start = std::time::Instant::now();
+
+        let extractor = Arc::clone(&self.extractor);
+        let source_clone = source.clone();
+        let auto_cleanup = self.auto_cleanup;
+        let extraction_stage_name = self.extractor.stage_name();
+
+        let extraction_result: Result<TempExtraction, PipelineError> = tokio::select! {
+            _ = cancel.cancelled() => Err(PipelineError::Cancelled),
+            res = timeout(self.stage_timeout, async move {
+                tokio::task::spawn_blocking(move || extractor.execute(source_clone, auto_cleanup)).await
+            }) => {
+                res
+                    .map_err(|_| PipelineError::StageTimeout {
+                        stage: extraction_stage_name.to_string(),
+                        timeout_secs: self.stage_timeout.as_secs(),
+                    })
+                    .and_then(|joined| {
+                        joined.map_err(|e| PipelineError::ExtractionFailed(format!("Task join error: {}", e)))
+                    })
+                    .and_then(|inner| inner.map_err(|e| PipelineError::ExtractionFailed(e.to_string())))
+            }
+        };
+
+        let mut temp = match extraction_result {
+            Ok(temp) => {
+                stats.extraction_duration_ms = extraction_start.elapsed().as_millis() as u64;
+                self.reporter
+                    .on_stage_complete(extraction_stage_name, stats.extraction_duration_ms);
+                let _ = events.send(HarvestEvent::ExtractionFinished {
+                    duration_ms: stats.extraction_duration_ms,
+                    path: temp.path.clone(),
+                });
+                temp
+            }
+            Err(e) => {
+                let duration_ms = extraction_start.elapsed().as_millis() as u64;
+                self.reporter
+                    .on_stage_failed(extraction_stage_name, &e, duration_ms);
+                self.reporter.on_package_failed(&source, &e);
+                self.reporter.on_run_end();
+                if let PipelineError::StageTimeout {
+                    stage,
+                    timeout_secs,
+                } = &e
+                {
+                    let _ = events.send(HarvestEvent::StageTimeout {
+                        stage: stage.clone(),
+                        timeout_secs: *timeout_secs,
+                    });
+                }
+                return Err(e);
+            }
+        };
+
+        temp.cleanup_on_drop = auto_cleanup;
+
+        // ====================================================================
+        // Stage 2: Analysis
+        // ====================================================================
+
+        let _ = events.send(HarvestEvent::AnalysisStarted);
+        let analysis_start = std::time::Instant::now();
+
+        let analyzer = Arc::clone(&self.analyzer);
+        let analysis_stage_name = self.analyzer.stage_name();
+        let extraction_path_for_result = if auto_cleanup {
+            None
+        } else {
+            Some(temp.path.clone())
+        };
+
+        let analysis_result: Result<(TempExtraction, HarvestMetadata), PipelineError> = tokio::select! {
+            _ = cancel.cancelled() => Err(PipelineError::Cancelled),
+            res = timeout(self.stage_timeout, async move {
+                tokio::task::spawn_blocking(move || analyzer.execute(temp)).await
+            }) => {
+                res
+                    .map_err(|_| PipelineError::StageTimeout {
+                        stage: analysis_stage_name.to_string(),
+                        timeout_secs: self.stage_timeout.as_secs(),
+                    })
+                    .and_then(|joined| {
+                        joined.map_err(|e| PipelineError::AnalysisFailed(format!("Task join error: {}", e)))
+                    })
+                    .and_then(|inner| inner.map_err(|e| PipelineError::AnalysisFailed(e.to_string())))
+            }
+        };
+
+        let (temp, metadata) = match analysis_result {
+            Ok((temp, metadata)) => {
+                stats.analysis_duration_ms = analysis_start.elapsed().as_millis() as u64;
+                self.reporter
+                    .on_stage_complete(analysis_stage_name, stats.analysis_duration_ms);
+                (temp, metadata)
+            }
+            Err(e) => {
+                let duration_ms = analysis_start.elapsed().as_millis() as u64;
+                self.reporter
+                    .on_stage_failed(analysis_stage_name, &e, duration_ms);
+                self.reporter.on_package_failed(&source, &e);
+                self.reporter.on_run_end();
+                if let PipelineError::StageTimeout {
+                    stage,
+                    timeout_secs,
+                } = &e
+                {
+                    let _ = events.send(HarvestEvent::StageTimeout {
+                        stage: stage.clone(),
+                        timeout_secs: *timeout_secs,
+                    });
+                }
+                return Err(e);
+            }
+        };
+
+        // ====================================================================
+        // Stage 3: Hashing
+        // ====================================================================
+
+        let hashing_start = std::time::Instant::now();
+        let hasher = Arc::clone(&self.hasher);
+        let hashing_stage_name = self.hasher.stage_name();
+
+        let hashing_result: Result<HashOutcome, PipelineError> = tokio::select! {
+            _ = cancel.cancelled() => Err(PipelineError::Cancelled),
+            res = timeout(self.stage_timeout, async move {
+                tokio::task::spawn_blocking(move || hasher.execute(metadata, temp)).await
+            }) => {
+                res
+                    .map_err(|_| PipelineError::StageTimeout {
+                        stage: hashing_stage_name.to_string(),
+                        timeout_secs: self.stage_timeout.as_secs(),
+                    })
+                    .and_then(|joined| {
+                        joined.map_err(|e| PipelineError::HashingFailed(format!("Task join error: {}", e)))
+                    })
+                    .and_then(|inner| inner.map_err(|e| PipelineError::HashingFailed(e.to_string())))
+            }
+        };
+
+        let hash_outcome = match hashing_result {
+            Ok(outcome) => {
+                stats.hashing_duration_ms = hashing_start.elapsed().as_millis() as u64;
+                self.reporter
+                    .on_stage_complete(hashing_stage_name, stats.hashing_duration_ms);
+                outcome
+            }
+            Err(e) => {
+                let duration_ms = hashing_start.elapsed().as_millis() as u64;
+                self.reporter
+                    .on_stage_failed(hashing_stage_name, &e, duration_ms);
+                self.reporter.on_package_failed(&source, &e);
+                self.reporter.on_run_end();
+                if let PipelineError::StageTimeout {
+                    stage,
+                    timeout_secs,
+                } = &e
+                {
+                    let _ = events.send(HarvestEvent::StageTimeout {
+                        stage: stage.clone(),
+                        timeout_secs: *timeout_secs,
+                    });
+                }
+                return Err(e);
+            }
+        };
+
+        stats.bytes_deduplicated = hash_outcome.bytes_deduplicated;
+        stats.unique_blocks_written = hash_outcome.unique_blocks_written;
+
+        let metadata = hash_outcome.metadata;
+        stats.files_processed = metadata.files.len();
+        stats.total_size_bytes = metadata.files.iter().map(|f| f.size).sum();
+
+        for file in &metadata.files {
+            let _ = events.send(HarvestEvent::FileProcessed {
+                path: file.path.clone(),
+                size: file.size,
+            });
+        }
+
+        stats.total_duration_ms = start.elapsed().as_millis() as u64;
+
+        if let Some(cache) = self.cache.clone() {
+            let put_source = source.clone();
+            let put_metadata = metadata.clone();
+            let put_result = tokio::task::spawn_blocking(move || {
+                let key = compute_cache_key(&put_source)?;
+                cache.put(&key, &put_metadata)
+            })
+            .await;
+
+            match put_result {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => warn!(error = %e, "Failed to write incremental cache entry"),
+                Err(e) => warn!(error = %e, "Cache write task panicked"),
+            }
+        }
+
+        let result = HarvestResult {
+            metadata,
+            extraction_path: extraction_path_for_result,
+            stats: stats.clone(),
+        };
+
+        self.reporter.on_package_complete(&result);
+        self.reporter.on_run_end();
+        let _ = events.send(HarvestEvent::Completed(stats));
+
+        Ok(result)
     }
 }
 
@@ -497,9 +1357,10 @@ mod tests {
     impl Analyzer for MockAnalyzer {
         fn execute(
             &self,
-            _input: TempExtraction,
-        ) -> Result<HarvestMetadata, Box<dyn std::error::Error + Send + Sync>> {
-            Ok(HarvestMetadata {
+            input: TempExtraction,
+        ) -> Result<(TempExtraction, HarvestMetadata), Box<dyn std::error::Error + Send + Sync>>
+        {
+            let metadata = HarvestMetadata {
                 source_format: "test".to_string(),
                 package_name: "test-package".to_string(),
                 version: "1.0.0".to_string(),
@@ -514,8 +1375,10 @@ mod tests {
                 capabilities: vec![],
                 harvest_timestamp: 1234567890,
                 harvester_version: env!("CARGO_PKG_VERSION").to_string(),
+                diagnostics: vec![],
                 extra: HashMap::new(),
-            })
+            };
+            Ok((input, metadata))
         }
 
         fn stage_name(&self) -> &'static str {
@@ -585,6 +1448,39 @@ mod tests {
         assert_eq!(result.stats.total_size_bytes, 12);
     }
 
+    #[tokio::test]
+    async fn test_harvest_batch_collects_all_results() {
+        let pipeline = HarvestPipeline::new(MockExtractor, MockAnalyzer).with_concurrency(2);
+
+        let sources = vec![
+            PathBuf::from("/tmp/batch_a.appimage"),
+            PathBuf::from("/tmp/batch_b.appimage"),
+            PathBuf::from("/tmp/batch_c.appimage"),
+        ];
+
+        let batch = pipeline.harvest_batch(sources).await;
+
+        assert_eq!(batch.results.len(), 3);
+        assert!(batch.results.iter().all(|r| r.is_ok()));
+        assert_eq!(batch.stats.files_processed, 3);
+        assert!(batch.shuffle_seed.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_harvest_batch_shuffle_seed_is_recorded() {
+        let pipeline = HarvestPipeline::new(MockExtractor, MockAnalyzer).with_shuffle_seed(42);
+
+        let sources = vec![
+            PathBuf::from("/tmp/batch_seed_a.appimage"),
+            PathBuf::from("/tmp/batch_seed_b.appimage"),
+        ];
+
+        let batch = pipeline.harvest_batch(sources).await;
+
+        assert_eq!(batch.shuffle_seed, Some(42));
+        assert_eq!(batch.results.len(), 2);
+    }
+
     #[test]
     fn test_safe_child_rejects_path_traversal() {
         let temp = TempExtraction {
@@ -604,4 +1500,71 @@ mod tests {
         // Normal relative path — allowed
         assert!(temp.safe_child(Path::new("subdir/file.txt")).is_ok());
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_safe_child_rejects_symlink_escaping_root() {
+        let dir =
+            std::env::temp_dir().join(format!("safe_child_symlink_test_{}", std::process::id()));
+        let root = dir.join("root");
+        let outside = dir.join("outside");
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::create_dir_all(&outside).unwrap();
+        std::os::unix::fs::symlink(&outside, root.join("escape")).unwrap();
+
+        let temp = TempExtraction {
+            path: root.clone(),
+            source_info: SourceInfo {
+                original_path: PathBuf::from("/pkg.appimage"),
+                size_bytes: 0,
+                detected_format: "test".to_string(),
+            },
+            cleanup_on_drop: false,
+        };
+
+        assert!(temp.safe_child(Path::new("escape")).is_err());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_execute_with_events_streams_progress_then_completes() {
+        let pipeline = Arc::new(HarvestPipeline::new(MockExtractor, MockAnalyzer));
+        let source = PathBuf::from("/tmp/events_test.appimage");
+
+        let (handle, mut rx, _cancel) = pipeline.execute_with_events(source);
+
+        let mut events = Vec::new();
+        while let Some(event) = rx.recv().await {
+            events.push(event);
+        }
+
+        let result = handle.await.unwrap();
+        assert!(result.is_ok());
+
+        assert!(matches!(
+            events.first(),
+            Some(HarvestEvent::ExtractionStarted)
+        ));
+        assert!(events
+            .iter()
+            .any(|e| matches!(e, HarvestEvent::AnalysisStarted)));
+        assert!(matches!(events.last(), Some(HarvestEvent::Completed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_execute_with_events_cancellation_is_reported() {
+        let pipeline = Arc::new(
+            HarvestPipeline::new(MockExtractor, MockAnalyzer).with_timeout(Duration::from_secs(60)),
+        );
+        let source = PathBuf::from("/tmp/events_cancel_test.appimage");
+
+        let (handle, mut rx, cancel) = pipeline.execute_with_events(source);
+        cancel.cancel();
+
+        while rx.recv().await.is_some() {}
+
+        let result = handle.await.unwrap();
+        assert!(matches!(result, Err(PipelineError::Cancelled)));
+    }
 }
@@ -0,0 +1,330 @@
+//! Platform/`cfg()` targeting for dependencies.
+//!
+//! Mirrors a useful subset of Rust's own `cfg()` mini-language so a
+//! [`Dependency`] can record exactly which targets it applies to, and
+//! [`HarvestMetadata::dependencies_for`] can filter down to the dependencies
+//! that matter for one target.
+
+use crate::harvest::traits::{Dependency, HarvestMetadata};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::iter::Peekable;
+use std::str::Chars;
+use thiserror::Error;
+
+/// How a [`Dependency`]'s `target` restricts which platforms it applies to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Platform {
+    /// An exact target triple, e.g. `"x86_64-unknown-linux-gnu"`.
+    Name(String),
+
+    /// A `cfg()` expression, e.g. `target_os = "linux"`.
+    Cfg(CfgExpr),
+}
+
+impl Platform {
+    /// Does this platform apply to the target described by `target_name`
+    /// (checked for [`Platform::Name`]) or by `cfg`/`idents` (checked for
+    /// [`Platform::Cfg`])?
+    pub fn matches(
+        &self,
+        target_name: &str,
+        cfg: &HashMap<String, String>,
+        idents: &HashSet<String>,
+    ) -> bool {
+        match self {
+            Platform::Name(name) => name == target_name,
+            Platform::Cfg(expr) => expr.matches(cfg, idents),
+        }
+    }
+}
+
+/// A parsed `cfg()` expression tree, e.g. `all(unix, target_arch = "x86_64")`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CfgExpr {
+    /// `all(a, b, ...)` — true when every inner expression is true.
+    All(Vec<CfgExpr>),
+
+    /// `any(a, b, ...)` — true when at least one inner expression is true.
+    Any(Vec<CfgExpr>),
+
+    /// `not(x)` — true when `x` is false.
+    Not(Box<CfgExpr>),
+
+    /// `key = "value"`, e.g. `target_os = "linux"`.
+    KeyValue { key: String, value: String },
+
+    /// A bare identifier, e.g. `unix` or `windows`.
+    Ident(String),
+}
+
+impl CfgExpr {
+    /// Evaluates this expression against a `key = "value"` map (`cfg`) and a
+    /// bare-identifier set (`idents`).
+    pub fn matches(&self, cfg: &HashMap<String, String>, idents: &HashSet<String>) -> bool {
+        match self {
+            CfgExpr::All(exprs) => exprs.iter().all(|expr| expr.matches(cfg, idents)),
+            CfgExpr::Any(exprs) => exprs.iter().any(|expr| expr.matches(cfg, idents)),
+            CfgExpr::Not(expr) => !expr.matches(cfg, idents),
+            CfgExpr::KeyValue { key, value } => cfg.get(key).is_some_and(|v| v == value),
+            CfgExpr::Ident(ident) => idents.contains(ident),
+        }
+    }
+
+    /// Parses a cfg-expression body, e.g. `"all(unix, target_arch = \"x86_64\")"`
+    /// (without an outer `cfg(...)` wrapper).
+    pub fn parse(input: &str) -> Result<CfgExpr, CfgParseError> {
+        let mut chars = input.chars().peekable();
+        let expr = parse_expr(&mut chars)?;
+
+        skip_whitespace(&mut chars);
+        if chars.peek().is_some() {
+            return Err(CfgParseError::TrailingInput(chars.collect()));
+        }
+
+        Ok(expr)
+    }
+}
+
+/// Errors produced while parsing a [`CfgExpr`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CfgParseError {
+    #[error("unexpected end of cfg expression")]
+    UnexpectedEnd,
+
+    #[error("expected '{0}'")]
+    ExpectedChar(char),
+
+    #[error("invalid cfg expression: {0}")]
+    Invalid(String),
+
+    #[error("unexpected trailing input: {0}")]
+    TrailingInput(String),
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while matches!(chars.peek(), Some(c) if c.is_whitespace()) {
+        chars.next();
+    }
+}
+
+fn parse_ident(chars: &mut Peekable<Chars>) -> Result<String, CfgParseError> {
+    skip_whitespace(chars);
+    let mut ident = String::new();
+    while matches!(chars.peek(), Some(c) if c.is_alphanumeric() || *c == '_') {
+        ident.push(chars.next().expect("peeked"));
+    }
+
+    if ident.is_empty() {
+        return Err(CfgParseError::UnexpectedEnd);
+    }
+
+    Ok(ident)
+}
+
+fn expect_char(chars: &mut Peekable<Chars>, expected: char) -> Result<(), CfgParseError> {
+    skip_whitespace(chars);
+    match chars.next() {
+        Some(c) if c == expected => Ok(()),
+        _ => Err(CfgParseError::ExpectedChar(expected)),
+    }
+}
+
+fn parse_quoted_string(chars: &mut Peekable<Chars>) -> Result<String, CfgParseError> {
+    expect_char(chars, '"')?;
+    let mut value = String::new();
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(value),
+            Some(c) => value.push(c),
+            None => return Err(CfgParseError::UnexpectedEnd),
+        }
+    }
+}
+
+/// Parses a parenthesized, comma-separated list of expressions, e.g. the
+/// `(unix, target_arch = "x86_64")` in `all(unix, target_arch = "x86_64")`.
+fn parse_expr_list(chars: &mut Peekable<Chars>) -> Result<Vec<CfgExpr>, CfgParseError> {
+    expect_char(chars, '(')?;
+    let mut exprs = Vec::new();
+
+    loop {
+        exprs.push(parse_expr(chars)?);
+        skip_whitespace(chars);
+        match chars.next() {
+            Some(',') => continue,
+            Some(')') => break,
+            _ => return Err(CfgParseError::ExpectedChar(')')),
+        }
+    }
+
+    Ok(exprs)
+}
+
+fn parse_expr(chars: &mut Peekable<Chars>) -> Result<CfgExpr, CfgParseError> {
+    let ident = parse_ident(chars)?;
+    skip_whitespace(chars);
+
+    match chars.peek() {
+        Some('(') if ident == "all" => Ok(CfgExpr::All(parse_expr_list(chars)?)),
+        Some('(') if ident == "any" => Ok(CfgExpr::Any(parse_expr_list(chars)?)),
+        Some('(') if ident == "not" => {
+            let mut inner = parse_expr_list(chars)?;
+            if inner.len() != 1 {
+                return Err(CfgParseError::Invalid(
+                    "not() takes exactly one expression".to_string(),
+                ));
+            }
+            Ok(CfgExpr::Not(Box::new(inner.remove(0))))
+        }
+        Some('(') => Err(CfgParseError::Invalid(format!(
+            "unknown cfg predicate '{ident}'"
+        ))),
+        Some('=') => {
+            chars.next();
+            skip_whitespace(chars);
+            let value = parse_quoted_string(chars)?;
+            Ok(CfgExpr::KeyValue { key: ident, value })
+        }
+        _ => Ok(CfgExpr::Ident(ident)),
+    }
+}
+
+/// Describes the target being resolved against, for
+/// [`HarvestMetadata::dependencies_for`]: a target-triple `name` plus the
+/// `key = "value"` pairs (`cfg`) and bare identifiers (`idents`) that would
+/// be true for it (e.g. `cfg: {"target_os": "linux"}`, `idents: {"unix"}`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TargetCfg {
+    pub name: String,
+    pub cfg: HashMap<String, String>,
+    pub idents: HashSet<String>,
+}
+
+impl TargetCfg {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            cfg: HashMap::new(),
+            idents: HashSet::new(),
+        }
+    }
+
+    pub fn with_cfg(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.cfg.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn with_ident(mut self, ident: impl Into<String>) -> Self {
+        self.idents.insert(ident.into());
+        self
+    }
+}
+
+impl HarvestMetadata {
+    /// Returns the dependencies that apply to `target`: those with no
+    /// `target` restriction, plus those whose [`Platform`] matches it.
+    pub fn dependencies_for(&self, target: &TargetCfg) -> Vec<&Dependency> {
+        self.dependencies
+            .iter()
+            .filter(|dep| match &dep.target {
+                None => true,
+                Some(platform) => platform.matches(&target.name, &target.cfg, &target.idents),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn linux_target() -> TargetCfg {
+        TargetCfg::new("x86_64-unknown-linux-gnu")
+            .with_cfg("target_os", "linux")
+            .with_cfg("target_arch", "x86_64")
+            .with_ident("unix")
+    }
+
+    #[test]
+    fn test_parse_bare_ident() {
+        let expr = CfgExpr::parse("unix").unwrap();
+        assert_eq!(expr, CfgExpr::Ident("unix".to_string()));
+    }
+
+    #[test]
+    fn test_parse_key_value() {
+        let expr = CfgExpr::parse(r#"target_os = "linux""#).unwrap();
+        assert_eq!(
+            expr,
+            CfgExpr::KeyValue {
+                key: "target_os".to_string(),
+                value: "linux".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_nested_all_any_not() {
+        let expr =
+            CfgExpr::parse(r#"all(unix, any(target_arch = "x86_64", not(windows)))"#).unwrap();
+        let cfg = HashMap::from([("target_arch".to_string(), "x86_64".to_string())]);
+        let idents = HashSet::from(["unix".to_string()]);
+        assert!(expr.matches(&cfg, &idents));
+    }
+
+    #[test]
+    fn test_matches_key_value_and_ident() {
+        let target = linux_target();
+        let expr = CfgExpr::parse(r#"target_os = "linux""#).unwrap();
+        assert!(expr.matches(&target.cfg, &target.idents));
+
+        let expr = CfgExpr::parse("windows").unwrap();
+        assert!(!expr.matches(&target.cfg, &target.idents));
+    }
+
+    #[test]
+    fn test_parse_rejects_trailing_input() {
+        assert!(matches!(
+            CfgExpr::parse("unix extra"),
+            Err(CfgParseError::TrailingInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_rejects_not_with_multiple_args() {
+        assert!(matches!(
+            CfgExpr::parse("not(unix, windows)"),
+            Err(CfgParseError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn test_dependencies_for_filters_by_platform() {
+        use crate::harvest::traits::DependencyKind;
+
+        let linux_only = Dependency::new("libx11-dev", None, DependencyKind::Runtime).with_target(
+            Platform::Cfg(CfgExpr::parse(r#"target_os = "linux""#).unwrap()),
+        );
+        let windows_only = Dependency::new("winapi", None, DependencyKind::Runtime)
+            .with_target(Platform::Cfg(CfgExpr::parse("windows").unwrap()));
+        let universal = Dependency::new("serde", Some("^1.0".to_string()), DependencyKind::Runtime);
+
+        let metadata = HarvestMetadata {
+            source_format: "cargo".to_string(),
+            package_name: "example".to_string(),
+            version: "0.1.0".to_string(),
+            dependencies: vec![linux_only, windows_only, universal],
+            files: vec![],
+            capabilities: vec![],
+            harvest_timestamp: 0,
+            harvester_version: "0.0.0".to_string(),
+            diagnostics: vec![],
+            extra: HashMap::new(),
+        };
+
+        let resolved = metadata.dependencies_for(&linux_target());
+        let names: Vec<&str> = resolved.iter().map(|dep| dep.name.as_str()).collect();
+        assert_eq!(names, vec!["libx11-dev", "serde"]);
+    }
+}
@@ -0,0 +1,333 @@
+//! Observability hooks for [`HarvestPipeline`](crate::harvest::pipeline::HarvestPipeline).
+//!
+//! Pipeline progress was previously only visible through `tracing` log
+//! lines. [`Reporter`] gives callers a structured way to observe (and
+//! render) the same events — a human/pretty reporter for interactive use,
+//! and a [`JUnitReporter`] so harvest runs can be ingested by CI dashboards
+//! that already understand JUnit XML.
+
+use crate::harvest::pipeline::{HarvestResult, PipelineError};
+use std::path::Path;
+use std::sync::Mutex;
+use tracing::{error, info, warn};
+
+/// Callbacks fired by [`HarvestPipeline::execute`](crate::harvest::pipeline::HarvestPipeline::execute)
+/// as it progresses through a harvest.
+///
+/// All methods have empty default implementations, so a reporter only needs
+/// to override the events it cares about.
+pub trait Reporter: Send + Sync {
+    /// Fired once at the start of a harvest run.
+    fn on_run_start(&self) {}
+
+    /// Fired when a package's pipeline begins executing.
+    fn on_package_start(&self, _source: &Path) {}
+
+    /// Fired when a stage completes successfully.
+    fn on_stage_complete(&self, _stage_name: &str, _duration_ms: u64) {}
+
+    /// Fired when a stage fails or times out.
+    fn on_stage_failed(&self, _stage_name: &str, _error: &PipelineError, _duration_ms: u64) {}
+
+    /// Fired when a package finishes harvesting successfully.
+    fn on_package_complete(&self, _result: &HarvestResult) {}
+
+    /// Fired when a package's pipeline fails before completion.
+    fn on_package_failed(&self, _source: &Path, _error: &PipelineError) {}
+
+    /// Fired once at the end of a harvest run.
+    fn on_run_end(&self) {}
+}
+
+/// Reporter used when a pipeline is built without
+/// [`with_reporter`](crate::harvest::pipeline::HarvestPipeline::with_reporter).
+///
+/// Accepts every callback and does nothing, relying purely on the
+/// pipeline's existing `tracing` log lines.
+pub struct NoopReporter;
+
+impl Reporter for NoopReporter {}
+
+/// Human-readable reporter that logs progress via `tracing`.
+///
+/// Intended for interactive/CLI use; emits one line per event at an
+/// appropriate level (`info` for progress, `warn`/`error` for failures).
+pub struct PrettyReporter;
+
+impl Reporter for PrettyReporter {
+    fn on_run_start(&self) {
+        info!("harvest run starting");
+    }
+
+    fn on_package_start(&self, source: &Path) {
+        info!(package = %source.display(), "harvesting package");
+    }
+
+    fn on_stage_complete(&self, stage_name: &str, duration_ms: u64) {
+        info!(stage = stage_name, duration_ms, "stage completed");
+    }
+
+    fn on_stage_failed(&self, stage_name: &str, error: &PipelineError, duration_ms: u64) {
+        if matches!(error, PipelineError::StageTimeout { .. }) {
+            error!(stage = stage_name, duration_ms, %error, "stage timed out");
+        } else {
+            warn!(stage = stage_name, duration_ms, %error, "stage failed");
+        }
+    }
+
+    fn on_package_complete(&self, result: &HarvestResult) {
+        info!(
+            package = %result.metadata.package_name,
+            version = %result.metadata.version,
+            duration_ms = result.stats.total_duration_ms,
+            "package harvested"
+        );
+    }
+
+    fn on_package_failed(&self, source: &Path, error: &PipelineError) {
+        error!(package = %source.display(), %error, "package harvest failed");
+    }
+
+    fn on_run_end(&self) {
+        info!("harvest run finished");
+    }
+}
+
+/// One `<testcase>` element — a single pipeline stage.
+struct TestCase {
+    stage_name: String,
+    duration_ms: u64,
+    /// `Some((is_error, message))` when the stage failed; `is_error`
+    /// distinguishes a timeout (`<error>`) from any other failure
+    /// (`<failure>`).
+    failure: Option<(bool, String)>,
+}
+
+/// One `<testsuite>` element — a single package's pipeline run.
+struct TestSuite {
+    package: String,
+    duration_ms: u64,
+    testcases: Vec<TestCase>,
+}
+
+#[derive(Default)]
+struct JUnitState {
+    /// Completed testsuites, in the order their packages finished.
+    suites: Vec<TestSuite>,
+
+    /// Testsuite currently being built, between `on_package_start` and the
+    /// matching `on_package_complete`/`on_package_failed`.
+    current: Option<TestSuite>,
+}
+
+/// Reporter that accumulates harvest events into a JUnit XML document.
+///
+/// Hierarchy mirrors JUnit's nested testsuite model: `<testsuites>` is the
+/// whole run, `<testsuite>` is one package file, `<testcase>` is one
+/// pipeline stage. Stage failures/timeouts become `<failure>`/`<error>`
+/// child elements carrying the [`PipelineError`] message.
+///
+/// Call [`to_xml`](Self::to_xml) once the run is finished (typically after
+/// `on_run_end`) to render the accumulated document.
+#[derive(Default)]
+pub struct JUnitReporter {
+    state: Mutex<JUnitState>,
+}
+
+impl JUnitReporter {
+    /// Creates an empty reporter.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Renders the accumulated events as a JUnit XML document.
+    pub fn to_xml(&self) -> String {
+        let state = self.state.lock().unwrap();
+
+        let total_tests: usize = state.suites.iter().map(|s| s.testcases.len()).sum();
+        let total_failures: usize = state
+            .suites
+            .iter()
+            .flat_map(|s| &s.testcases)
+            .filter(|tc| matches!(tc.failure, Some((false, _))))
+            .count();
+        let total_errors: usize = state
+            .suites
+            .iter()
+            .flat_map(|s| &s.testcases)
+            .filter(|tc| matches!(tc.failure, Some((true, _))))
+            .count();
+
+        let mut xml = String::new();
+        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str(&format!(
+            "<testsuites tests=\"{total_tests}\" failures=\"{total_failures}\" errors=\"{total_errors}\">\n"
+        ));
+
+        for suite in &state.suites {
+            let suite_time = suite.duration_ms as f64 / 1000.0;
+            xml.push_str(&format!(
+                "  <testsuite name=\"{}\" tests=\"{}\" time=\"{:.3}\">\n",
+                xml_escape(&suite.package),
+                suite.testcases.len(),
+                suite_time
+            ));
+
+            for testcase in &suite.testcases {
+                let tc_time = testcase.duration_ms as f64 / 1000.0;
+                match &testcase.failure {
+                    None => {
+                        xml.push_str(&format!(
+                            "    <testcase name=\"{}\" time=\"{:.3}\"/>\n",
+                            xml_escape(&testcase.stage_name),
+                            tc_time
+                        ));
+                    }
+                    Some((is_error, message)) => {
+                        let tag = if *is_error { "error" } else { "failure" };
+                        xml.push_str(&format!(
+                            "    <testcase name=\"{}\" time=\"{:.3}\">\n",
+                            xml_escape(&testcase.stage_name),
+                            tc_time
+                        ));
+                        xml.push_str(&format!(
+                            "      <{tag} message=\"{}\"/>\n",
+                            xml_escape(message)
+                        ));
+                        xml.push_str("    </testcase>\n");
+                    }
+                }
+            }
+
+            xml.push_str("  </testsuite>\n");
+        }
+
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+}
+
+impl Reporter for JUnitReporter {
+    fn on_package_start(&self, source: &Path) {
+        let mut state = self.state.lock().unwrap();
+        state.current = Some(TestSuite {
+            package: source.display().to_string(),
+            duration_ms: 0,
+            testcases: Vec::new(),
+        });
+    }
+
+    fn on_stage_complete(&self, stage_name: &str, duration_ms: u64) {
+        let mut state = self.state.lock().unwrap();
+        if let Some(suite) = state.current.as_mut() {
+            suite.testcases.push(TestCase {
+                stage_name: stage_name.to_string(),
+                duration_ms,
+                failure: None,
+            });
+        }
+    }
+
+    fn on_stage_failed(&self, stage_name: &str, error: &PipelineError, duration_ms: u64) {
+        let is_error = matches!(error, PipelineError::StageTimeout { .. });
+        let mut state = self.state.lock().unwrap();
+        if let Some(suite) = state.current.as_mut() {
+            suite.testcases.push(TestCase {
+                stage_name: stage_name.to_string(),
+                duration_ms,
+                failure: Some((is_error, error.to_string())),
+            });
+        }
+    }
+
+    fn on_package_complete(&self, result: &HarvestResult) {
+        let mut state = self.state.lock().unwrap();
+        if let Some(mut suite) = state.current.take() {
+            suite.duration_ms = result.stats.total_duration_ms;
+            state.suites.push(suite);
+        }
+    }
+
+    fn on_package_failed(&self, _source: &Path, _error: &PipelineError) {
+        let mut state = self.state.lock().unwrap();
+        if let Some(suite) = state.current.take() {
+            state.suites.push(suite);
+        }
+    }
+}
+
+fn xml_escape(input: &str) -> String {
+    input
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::harvest::pipeline::HarvestStats;
+    use crate::harvest::traits::HarvestMetadata;
+    use std::collections::HashMap;
+
+    fn sample_result() -> HarvestResult {
+        HarvestResult {
+            metadata: HarvestMetadata {
+                source_format: "test".to_string(),
+                package_name: "demo".to_string(),
+                version: "1.0.0".to_string(),
+                dependencies: vec![],
+                files: vec![],
+                capabilities: vec![],
+                harvest_timestamp: 0,
+                harvester_version: "0.0.0".to_string(),
+                diagnostics: vec![],
+                extra: HashMap::new(),
+            },
+            extraction_path: None,
+            stats: HarvestStats {
+                total_duration_ms: 42,
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_junit_reporter_renders_successful_package() {
+        let reporter = JUnitReporter::new();
+        reporter.on_package_start(Path::new("demo.AppImage"));
+        reporter.on_stage_complete("extraction", 10);
+        reporter.on_stage_complete("analysis", 20);
+        reporter.on_package_complete(&sample_result());
+
+        let xml = reporter.to_xml();
+        assert!(xml.contains("<testsuites tests=\"2\" failures=\"0\" errors=\"0\">"));
+        assert!(xml.contains("name=\"demo.AppImage\""));
+        assert!(xml.contains("name=\"extraction\""));
+        assert!(xml.contains("name=\"analysis\""));
+    }
+
+    #[test]
+    fn test_junit_reporter_distinguishes_timeout_from_failure() {
+        let reporter = JUnitReporter::new();
+        reporter.on_package_start(Path::new("demo.AppImage"));
+        reporter.on_stage_failed(
+            "extraction",
+            &PipelineError::StageTimeout {
+                stage: "extraction".to_string(),
+                timeout_secs: 5,
+            },
+            5000,
+        );
+        reporter.on_package_failed(
+            Path::new("demo.AppImage"),
+            &PipelineError::ExtractionFailed("timeout".to_string()),
+        );
+
+        let xml = reporter.to_xml();
+        assert!(xml.contains("<error message="));
+        assert!(!xml.contains("<failure message="));
+    }
+}
@@ -6,6 +6,9 @@
 //! - Normalized metadata structures via [`HarvestMetadata`]
 //! - Standardized error handling
 
+use crate::harvest::diagnostics::Diagnostic;
+use crate::harvest::platform::Platform;
+use semver::VersionReq;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -218,6 +221,12 @@ pub struct HarvestMetadata {
     /// Should follow semver format matching the harvester crate version
     pub harvester_version: String,
 
+    /// Non-fatal problems found while analyzing the package (a missing
+    /// optional desktop key, an unparseable dependency line, ...), so a
+    /// stage can surface them as warnings instead of either swallowing them
+    /// or aborting [`PackageFormat::analyze`].
+    pub diagnostics: Vec<Diagnostic>,
+
     /// Format-specific additional metadata
     ///
     /// This field is flattened during serialization, allowing format-specific
@@ -232,13 +241,52 @@ pub struct Dependency {
     /// Dependency name (normalized when possible)
     pub name: String,
 
-    /// Optional version constraint
+    /// Optional version constraint, kept verbatim for round-tripping
     ///
     /// Examples: `"^2.31"`, `">=3.24"`, `"~1.0.0"`
     pub version_constraint: Option<String>,
 
+    /// `version_constraint` parsed as a [`VersionReq`], when it's valid
+    /// semver syntax. `None` for ecosystems with looser versioning (e.g. a
+    /// npm `req` that isn't valid semver) rather than failing the whole
+    /// dependency.
+    pub version_req: Option<VersionReq>,
+
     /// Type of dependency
     pub kind: DependencyKind,
+
+    /// Platform this dependency is restricted to (exact triple or `cfg()`
+    /// expression), if any. `None` means it applies everywhere.
+    pub target: Option<Platform>,
+}
+
+impl Dependency {
+    /// Builds a [`Dependency`] with no platform restriction, parsing
+    /// `version_constraint` into [`Dependency::version_req`] when it's
+    /// present and valid semver.
+    pub fn new(
+        name: impl Into<String>,
+        version_constraint: Option<String>,
+        kind: DependencyKind,
+    ) -> Self {
+        let version_req = version_constraint
+            .as_deref()
+            .and_then(|constraint| VersionReq::parse(constraint).ok());
+
+        Self {
+            name: name.into(),
+            version_constraint,
+            version_req,
+            kind,
+            target: None,
+        }
+    }
+
+    /// Restricts this dependency to `target`.
+    pub fn with_target(mut self, target: Platform) -> Self {
+        self.target = Some(target);
+        self
+    }
 }
 
 /// Classification of dependency types.
@@ -260,9 +308,11 @@ pub struct FileDescriptor {
     /// Path relative to package root
     pub path: PathBuf,
 
-    /// BLAKE3 hash of file contents
+    /// Hex-encoded multihash of the file contents (see
+    /// [`crate::harvest::cas`]).
     ///
-    /// Will be `None` until Phase 2 implements content hashing
+    /// Populated by the hashing stage; stays `None` when the pipeline uses
+    /// [`crate::harvest::hasher::NoopHasher`] (the default).
     pub hash: Option<String>,
 
     /// File size in bytes
@@ -413,11 +463,7 @@ mod tests {
 
     #[test]
     fn test_dependency_kind_serialization() {
-        let dep = Dependency {
-            name: "glib".to_string(),
-            version_constraint: Some("^2.0".to_string()),
-            kind: DependencyKind::Runtime,
-        };
+        let dep = Dependency::new("glib", Some("^2.0".to_string()), DependencyKind::Runtime);
 
         let json = serde_json::to_string(&dep).unwrap();
         let deserialized: Dependency = serde_json::from_str(&json).unwrap();
@@ -425,4 +471,24 @@ mod tests {
         assert_eq!(deserialized.name, dep.name);
         assert_eq!(deserialized.kind, DependencyKind::Runtime);
     }
+
+    #[test]
+    fn test_dependency_new_parses_valid_semver_constraint() {
+        let dep = Dependency::new("glib", Some("^2.0".to_string()), DependencyKind::Runtime);
+        assert!(dep.version_req.is_some());
+    }
+
+    #[test]
+    fn test_dependency_new_degrades_gracefully_on_invalid_constraint() {
+        let dep = Dependency::new(
+            "foo",
+            Some("not a semver range".to_string()),
+            DependencyKind::Runtime,
+        );
+        assert_eq!(
+            dep.version_constraint.as_deref(),
+            Some("not a semver range")
+        );
+        assert!(dep.version_req.is_none());
+    }
 }
@@ -1,8 +1,17 @@
+pub mod audit;
 pub mod executor;
+pub mod graph;
+pub mod harvest;
 pub mod model;
+pub mod parsers;
+pub mod registry;
 pub mod traits;
 
 // Re-export common types for convenience
+pub use audit::*;
 pub use executor::*;
+pub use graph::*;
 pub use model::*;
+pub use parsers::*;
+pub use registry::*;
 pub use traits::*;
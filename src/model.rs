@@ -1,4 +1,6 @@
+use semver::{Version, VersionReq};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HarvesterBatch {
@@ -7,6 +9,38 @@ pub struct HarvesterBatch {
     pub source_vcs: Option<LocalVcsRef>,
 }
 
+impl HarvesterBatch {
+    /// Flags which nodes fall inside a vulnerability's affected ranges.
+    ///
+    /// Every node is checked against every vulnerability in the batch — this
+    /// type has no per-vulnerability package reference, so callers are
+    /// expected to have already scoped `vulnerabilities` to the ecosystem(s)
+    /// `nodes` belongs to. A node whose `version` isn't valid semver is
+    /// skipped rather than failing the whole batch, since ecosystems like
+    /// npm allow looser version strings.
+    pub fn flag_vulnerable(&self) -> Vec<(String, String)> {
+        let mut flagged = Vec::new();
+
+        for node in &self.nodes {
+            let Ok(version) = Version::parse(&node.version) else {
+                continue;
+            };
+
+            for vuln in &self.vulnerabilities {
+                if vuln
+                    .affected_ranges
+                    .iter()
+                    .any(|range| range.matches(&version))
+                {
+                    flagged.push((node.name.clone(), vuln.id.clone()));
+                }
+            }
+        }
+
+        flagged
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LocalPackageNode {
     pub name: String,
@@ -15,6 +49,38 @@ pub struct LocalPackageNode {
     pub description: Option<String>,
     pub license: Option<String>,
     pub dependencies: Vec<String>, // Simple list of dep names/ranges for now
+
+    /// How each entry in `dependencies` is used, keyed by dependency name,
+    /// for ecosystems whose manifest format distinguishes runtime from
+    /// build/dev-only dependencies (currently only [`crate::parsers::CargoParser`],
+    /// via Cargo's `dependencies`/`dev-dependencies`/`build-dependencies`).
+    /// A name missing here (most ecosystems) is assumed [`EdgeKind::Normal`];
+    /// `CargoParser` records a `null` Cargo `kind` as [`EdgeKind::Normal`].
+    pub dependency_kinds: HashMap<String, EdgeKind>,
+
+    /// Each entry in `dependencies`' raw `cfg()` target restriction, keyed
+    /// by dependency name (e.g. Cargo's `target: "cfg(unix)"`), for parsers
+    /// that can't resolve it to a [`crate::harvest::platform::Platform`]
+    /// themselves. A name missing here applies to every target.
+    pub dependency_targets: HashMap<String, String>,
+}
+
+/// How a dependency edge is used, preserved from the ecosystem's own
+/// manifest distinction so downstream tooling (e.g. [`crate::audit`]) isn't
+/// stuck inferring it from reachability alone.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EdgeKind {
+    /// An ordinary, always-needed dependency.
+    #[default]
+    Normal,
+
+    /// Needed only to build the depending package (e.g. a build script
+    /// dependency), not at its runtime.
+    Build,
+
+    /// Needed only for the depending package's own tests/examples, never
+    /// shipped with it.
+    Dev,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,6 +89,41 @@ pub struct LocalVulnerability {
     pub severity: String, // "Critical", "High", etc.
     pub description: String,
     pub affected_versions: String,
+
+    /// `affected_versions` parsed into semver [`VersionReq`]s.
+    ///
+    /// `affected_versions` may describe several disjoint ranges joined by
+    /// `;` (e.g. `"<1.0.0;>=2.0.0, <2.3.0"`); each segment is parsed on its
+    /// own, and a comma-joined segment like `">=1.0.0, <1.4.2"` parses as a
+    /// single `VersionReq` whose comparators are ANDed together. Segments
+    /// that aren't valid semver are dropped rather than failing the whole
+    /// vulnerability.
+    pub affected_ranges: Vec<VersionReq>,
+}
+
+impl LocalVulnerability {
+    /// Builds a [`LocalVulnerability`], parsing `affected_versions` into
+    /// [`LocalVulnerability::affected_ranges`].
+    pub fn new(
+        id: impl Into<String>,
+        severity: impl Into<String>,
+        description: impl Into<String>,
+        affected_versions: impl Into<String>,
+    ) -> Self {
+        let affected_versions = affected_versions.into();
+        let affected_ranges = affected_versions
+            .split(';')
+            .filter_map(|segment| VersionReq::parse(segment.trim()).ok())
+            .collect();
+
+        Self {
+            id: id.into(),
+            severity: severity.into(),
+            description: description.into(),
+            affected_versions,
+            affected_ranges,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,3 +132,81 @@ pub struct LocalVcsRef {
     pub commit: Option<String>,
     pub tag: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(name: &str, version: &str) -> LocalPackageNode {
+        LocalPackageNode {
+            name: name.to_string(),
+            version: version.to_string(),
+            ecosystem: "cargo".to_string(),
+            description: None,
+            license: None,
+            dependencies: vec![],
+            dependency_kinds: HashMap::new(),
+            dependency_targets: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_local_vulnerability_new_parses_comma_joined_range() {
+        let vuln = LocalVulnerability::new("CVE-2023-0001", "High", "oops", ">=1.0.0, <1.4.2");
+        assert_eq!(vuln.affected_ranges.len(), 1);
+        assert!(vuln.affected_ranges[0].matches(&Version::parse("1.2.0").unwrap()));
+        assert!(!vuln.affected_ranges[0].matches(&Version::parse("1.4.2").unwrap()));
+    }
+
+    #[test]
+    fn test_local_vulnerability_new_parses_semicolon_joined_ranges() {
+        let vuln =
+            LocalVulnerability::new("CVE-2023-0002", "High", "oops", "<1.0.0;>=2.0.0, <2.3.0");
+        assert_eq!(vuln.affected_ranges.len(), 2);
+    }
+
+    #[test]
+    fn test_local_vulnerability_new_drops_unparseable_segments() {
+        let vuln = LocalVulnerability::new("CVE-2023-0003", "Low", "oops", "not a range");
+        assert!(vuln.affected_ranges.is_empty());
+    }
+
+    #[test]
+    fn test_flag_vulnerable_matches_affected_nodes_only() {
+        let batch = HarvesterBatch {
+            nodes: vec![node("serde", "1.2.0"), node("anyhow", "1.0.0")],
+            vulnerabilities: vec![LocalVulnerability::new(
+                "CVE-2023-0001",
+                "High",
+                "oops",
+                ">=1.0.0, <1.4.2",
+            )],
+            source_vcs: None,
+        };
+
+        let flagged = batch.flag_vulnerable();
+        assert_eq!(
+            flagged,
+            vec![
+                ("serde".to_string(), "CVE-2023-0001".to_string()),
+                ("anyhow".to_string(), "CVE-2023-0001".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_flag_vulnerable_skips_unparseable_node_versions() {
+        let batch = HarvesterBatch {
+            nodes: vec![node("weird", "not-a-version")],
+            vulnerabilities: vec![LocalVulnerability::new(
+                "CVE-2023-0001",
+                "High",
+                "oops",
+                "*",
+            )],
+            source_vcs: None,
+        };
+
+        assert!(batch.flag_vulnerable().is_empty());
+    }
+}
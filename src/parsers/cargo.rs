@@ -0,0 +1,339 @@
+//! [`EcosystemParser`] for Rust workspaces, backed by the JSON `cargo
+//! metadata --format-version 1` emits rather than hand-parsed `Cargo.toml`.
+//!
+//! Using `cargo metadata`'s output means dependency requirements, feature
+//! resolution, and workspace membership all come pre-resolved from the
+//! canonical tool instead of being reimplemented here.
+
+use crate::model::{EdgeKind, HarvesterBatch, LocalPackageNode, LocalVcsRef};
+use crate::traits::{EcosystemParser, ParseError};
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// Top-level shape of `cargo metadata --format-version 1` output, trimmed to
+/// the fields this parser needs.
+#[derive(Debug, Deserialize)]
+struct CargoMetadata {
+    packages: Vec<CargoPackage>,
+    resolve: Option<CargoResolve>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoPackage {
+    id: String,
+    name: String,
+    version: String,
+    description: Option<String>,
+    license: Option<String>,
+    source: Option<String>,
+    dependencies: Vec<CargoDependency>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoDependency {
+    name: String,
+    req: String,
+
+    /// `"normal"`, `"dev"`, `"build"`, or `null` (treated as `"normal"`).
+    kind: Option<String>,
+
+    /// The dependency's `cfg()` target restriction, e.g.
+    /// `cfg(unix)`/`cfg(windows)`, or `null` for a universal dependency.
+    target: Option<String>,
+}
+
+impl CargoDependency {
+    fn edge_kind(&self) -> EdgeKind {
+        match self.kind.as_deref() {
+            Some("dev") => EdgeKind::Dev,
+            Some("build") => EdgeKind::Build,
+            _ => EdgeKind::Normal,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoResolve {
+    nodes: Vec<CargoResolveNode>,
+    root: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoResolveNode {
+    id: String,
+    dependencies: Vec<String>,
+}
+
+/// Parses `cargo metadata --format-version 1` JSON into a [`HarvesterBatch`].
+#[derive(Debug, Default)]
+pub struct CargoParser;
+
+#[async_trait]
+impl EcosystemParser for CargoParser {
+    fn ecosystem_id(&self) -> &str {
+        "cargo"
+    }
+
+    async fn parse(&self, content: &[u8]) -> Result<HarvesterBatch, ParseError> {
+        let doc: CargoMetadata = serde_json::from_slice(content)
+            .map_err(|e| ParseError::InvalidContent(e.to_string()))?;
+
+        let by_id: HashMap<&str, &CargoPackage> =
+            doc.packages.iter().map(|p| (p.id.as_str(), p)).collect();
+
+        let mut nodes: Vec<LocalPackageNode> = match &doc.resolve {
+            Some(resolve) => resolve
+                .nodes
+                .iter()
+                .filter_map(|node| by_id.get(node.id.as_str()).map(|pkg| (*pkg, node)))
+                .map(|(pkg, node)| package_node(pkg, resolved_dependencies(pkg, node, &by_id)))
+                .collect(),
+            None => doc
+                .packages
+                .iter()
+                .map(|pkg| package_node(pkg, declared_dependencies(pkg)))
+                .collect(),
+        };
+
+        // `resolve.nodes` is not root-first; move the `resolve.root` package
+        // to the front so `nodes.first()` is the crate under analysis.
+        let root_pkg = doc
+            .resolve
+            .as_ref()
+            .and_then(|resolve| resolve.root.as_deref())
+            .and_then(|root_id| by_id.get(root_id));
+        if let Some(root) = root_pkg {
+            if let Some(pos) = nodes
+                .iter()
+                .position(|n| n.name == root.name && n.version == root.version)
+            {
+                let root_node = nodes.remove(pos);
+                nodes.insert(0, root_node);
+            }
+        }
+
+        let source_vcs = doc
+            .resolve
+            .as_ref()
+            .and_then(|resolve| resolve.root.as_deref())
+            .and_then(|root_id| by_id.get(root_id))
+            .and_then(|pkg| pkg.source.as_deref())
+            .and_then(parse_git_vcs_ref);
+
+        Ok(HarvesterBatch {
+            nodes,
+            vulnerabilities: vec![],
+            source_vcs,
+        })
+    }
+}
+
+/// A package's dependency edges in the three shapes [`LocalPackageNode`]
+/// needs: the flat `"name req"` display strings plus per-name side tables
+/// for the edge kind and raw `cfg()` target, which don't fit in the
+/// strings without breaking every consumer that splits them on whitespace.
+#[derive(Default)]
+struct DependencyEdges {
+    strings: Vec<String>,
+    kinds: HashMap<String, EdgeKind>,
+    targets: HashMap<String, String>,
+}
+
+/// Builds a [`LocalPackageNode`] for `pkg` from its resolved `edges`.
+fn package_node(pkg: &CargoPackage, edges: DependencyEdges) -> LocalPackageNode {
+    LocalPackageNode {
+        name: pkg.name.clone(),
+        version: pkg.version.clone(),
+        ecosystem: "cargo".to_string(),
+        description: pkg.description.clone(),
+        license: pkg.license.clone(),
+        dependencies: edges.strings,
+        dependency_kinds: edges.kinds,
+        dependency_targets: edges.targets,
+    }
+}
+
+/// Builds `pkg`'s resolved dependency edges (from `resolve.nodes`), looking
+/// the requirement, kind, and target up on the depending package's own
+/// declared `dependencies` entry.
+fn resolved_dependencies(
+    pkg: &CargoPackage,
+    node: &CargoResolveNode,
+    by_id: &HashMap<&str, &CargoPackage>,
+) -> DependencyEdges {
+    let mut edges = DependencyEdges::default();
+
+    for dep_pkg in node
+        .dependencies
+        .iter()
+        .filter_map(|dep_id| by_id.get(dep_id.as_str()))
+    {
+        let declared = pkg.dependencies.iter().find(|d| d.name == dep_pkg.name);
+        let req = declared.map(|d| d.req.as_str()).unwrap_or("*");
+        edges.strings.push(format!("{} {}", dep_pkg.name, req));
+
+        if let Some(declared) = declared {
+            edges
+                .kinds
+                .insert(dep_pkg.name.clone(), declared.edge_kind());
+            if let Some(target) = &declared.target {
+                edges.targets.insert(dep_pkg.name.clone(), target.clone());
+            }
+        }
+    }
+
+    edges
+}
+
+/// Builds `pkg`'s declared dependency edges, used when `cargo metadata` was
+/// run without a `resolve` graph (e.g. `--no-deps`).
+fn declared_dependencies(pkg: &CargoPackage) -> DependencyEdges {
+    let mut edges = DependencyEdges::default();
+
+    for dep in &pkg.dependencies {
+        edges.strings.push(format!("{} {}", dep.name, dep.req));
+        edges.kinds.insert(dep.name.clone(), dep.edge_kind());
+        if let Some(target) = &dep.target {
+            edges.targets.insert(dep.name.clone(), target.clone());
+        }
+    }
+
+    edges
+}
+
+/// Parses a `source` field shaped like `git+https://host/repo#<commit>` into
+/// a [`LocalVcsRef`], splitting the fragment off as the commit. Returns
+/// `None` for registry sources (`registry+...`), path dependencies, or any
+/// `git+` source without a `#` fragment.
+fn parse_git_vcs_ref(source: &str) -> Option<LocalVcsRef> {
+    let rest = source.strip_prefix("git+")?;
+    let (url, commit) = rest.split_once('#')?;
+    Some(LocalVcsRef {
+        url: url.to_string(),
+        commit: Some(commit.to_string()),
+        tag: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_metadata() -> &'static str {
+        r#"{
+            "packages": [
+                {
+                    "id": "root 0.1.0 (path+file:///workspace/root)",
+                    "name": "root",
+                    "version": "0.1.0",
+                    "description": "root crate",
+                    "license": "MIT",
+                    "source": "git+https://github.com/example/root#abc123",
+                    "dependencies": [
+                        { "name": "serde", "req": "^1.0", "kind": null, "target": null }
+                    ]
+                },
+                {
+                    "id": "serde 1.0.0 (registry+https://github.com/rust-lang/crates.io-index)",
+                    "name": "serde",
+                    "version": "1.0.0",
+                    "description": "serialization framework",
+                    "license": "MIT OR Apache-2.0",
+                    "source": "registry+https://github.com/rust-lang/crates.io-index",
+                    "dependencies": []
+                }
+            ],
+            "resolve": {
+                "nodes": [
+                    {
+                        "id": "root 0.1.0 (path+file:///workspace/root)",
+                        "dependencies": ["serde 1.0.0 (registry+https://github.com/rust-lang/crates.io-index)"]
+                    },
+                    {
+                        "id": "serde 1.0.0 (registry+https://github.com/rust-lang/crates.io-index)",
+                        "dependencies": []
+                    }
+                ],
+                "root": "root 0.1.0 (path+file:///workspace/root)"
+            }
+        }"#
+    }
+
+    #[tokio::test]
+    async fn test_parse_builds_one_node_per_resolved_package() {
+        let parser = CargoParser;
+        let batch = parser.parse(sample_metadata().as_bytes()).await.unwrap();
+
+        assert_eq!(batch.nodes.len(), 2);
+        let root = batch.nodes.iter().find(|n| n.name == "root").unwrap();
+        assert_eq!(root.dependencies, vec!["serde ^1.0".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_parse_fills_source_vcs_from_root_package() {
+        let parser = CargoParser;
+        let batch = parser.parse(sample_metadata().as_bytes()).await.unwrap();
+
+        let vcs = batch.source_vcs.expect("root package has a git source");
+        assert_eq!(vcs.url, "https://github.com/example/root");
+        assert_eq!(vcs.commit, Some("abc123".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_parse_ignores_registry_source_for_vcs() {
+        let parser = CargoParser;
+        let metadata = sample_metadata().replace(
+            r#""root": "root 0.1.0 (path+file:///workspace/root)""#,
+            r#""root": "serde 1.0.0 (registry+https://github.com/rust-lang/crates.io-index)""#,
+        );
+
+        let batch = parser.parse(metadata.as_bytes()).await.unwrap();
+        assert!(batch.source_vcs.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_parse_threads_dependency_kind_and_target_without_resolve() {
+        let metadata = r#"{
+            "packages": [
+                {
+                    "id": "root 0.1.0 (path+file:///workspace/root)",
+                    "name": "root",
+                    "version": "0.1.0",
+                    "description": null,
+                    "license": null,
+                    "source": null,
+                    "dependencies": [
+                        { "name": "serde", "req": "^1.0", "kind": null, "target": null },
+                        { "name": "criterion", "req": "^0.5", "kind": "dev", "target": null },
+                        { "name": "cc", "req": "^1.0", "kind": "build", "target": "cfg(unix)" }
+                    ]
+                }
+            ],
+            "resolve": null
+        }"#;
+
+        let parser = CargoParser;
+        let batch = parser.parse(metadata.as_bytes()).await.unwrap();
+        let root = batch.nodes.iter().find(|n| n.name == "root").unwrap();
+
+        assert_eq!(
+            root.dependency_kinds
+                .get("serde")
+                .copied()
+                .unwrap_or_default(),
+            EdgeKind::Normal
+        );
+        assert_eq!(root.dependency_kinds["criterion"], EdgeKind::Dev);
+        assert_eq!(root.dependency_kinds["cc"], EdgeKind::Build);
+        assert_eq!(root.dependency_targets["cc"], "cfg(unix)");
+        assert!(!root.dependency_targets.contains_key("serde"));
+    }
+
+    #[tokio::test]
+    async fn test_parse_rejects_invalid_json() {
+        let parser = CargoParser;
+        let result = parser.parse(b"not json").await;
+        assert!(matches!(result, Err(ParseError::InvalidContent(_))));
+    }
+}
@@ -0,0 +1,10 @@
+//! First-party [`EcosystemParser`](crate::traits::EcosystemParser)
+//! implementations.
+//!
+//! Each submodule understands one ecosystem's native tooling output (rather
+//! than hand-rolling manifest parsing) and is meant to be registered with a
+//! [`ParserRegistry`](crate::registry::ParserRegistry).
+
+pub mod cargo;
+
+pub use cargo::CargoParser;
@@ -0,0 +1,198 @@
+//! Dispatches raw manifest content to the right [`EcosystemParser`].
+//!
+//! [`EcosystemParser`] handles one ecosystem at a time; [`ParserRegistry`]
+//! holds every parser the harvester knows about and routes a `(filename,
+//! content)` pair to the correct one, so wiring in a new ecosystem is a
+//! single [`register`](ParserRegistry::register) call rather than an edit to
+//! a dispatch `match`.
+
+use crate::model::HarvesterBatch;
+use crate::traits::{EcosystemParser, ParseError};
+use std::collections::HashMap;
+
+/// Maps a manifest filename convention to the ecosystem ID that owns it.
+///
+/// Checked in order; the first matching filename wins.
+const FILENAME_CONVENTIONS: &[(&str, &str)] = &[
+    ("package.json", "npm"),
+    ("Cargo.toml", "cargo"),
+    ("requirements.txt", "pypi"),
+    ("pyproject.toml", "pypi"),
+    ("go.mod", "go"),
+    ("composer.json", "composer"),
+    ("Gemfile", "rubygems"),
+];
+
+/// Registry of [`EcosystemParser`] implementations, dispatched by filename
+/// convention with a priority-ordered fallback.
+pub struct ParserRegistry {
+    /// Registered parsers, indexed by `ecosystem_id` for convention lookups.
+    by_ecosystem: HashMap<String, Box<dyn EcosystemParser>>,
+
+    /// Registration order, used as fallback priority when no filename
+    /// convention matches (or the matched parser isn't registered).
+    priority: Vec<String>,
+}
+
+impl Default for ParserRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParserRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self {
+            by_ecosystem: HashMap::new(),
+            priority: Vec::new(),
+        }
+    }
+
+    /// Registers `parser`, keyed by its [`EcosystemParser::ecosystem_id`].
+    ///
+    /// Registering a second parser for the same ecosystem ID replaces the
+    /// first but keeps its original priority position.
+    pub fn register(&mut self, parser: Box<dyn EcosystemParser>) {
+        let ecosystem_id = parser.ecosystem_id().to_string();
+        if !self.by_ecosystem.contains_key(&ecosystem_id) {
+            self.priority.push(ecosystem_id.clone());
+        }
+        self.by_ecosystem.insert(ecosystem_id, parser);
+    }
+
+    /// Looks up the ecosystem ID `filename` conventionally belongs to (e.g.
+    /// `Cargo.toml` -> `"cargo"`), if any.
+    fn ecosystem_for_filename(filename: &str) -> Option<&'static str> {
+        FILENAME_CONVENTIONS
+            .iter()
+            .find(|(name, _)| *name == filename)
+            .map(|(_, ecosystem_id)| *ecosystem_id)
+    }
+
+    /// Parses `content` into a [`HarvesterBatch`], dispatching by `filename`
+    /// convention first (e.g. `package.json` -> the registered `npm`
+    /// parser).
+    ///
+    /// If `filename` doesn't match a known convention, or the conventionally
+    /// correct parser isn't registered, falls back to trying every
+    /// registered parser in registration order and returning the first
+    /// success.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::Unknown`] if no registered parser can make
+    /// sense of `content`.
+    pub async fn parse_auto(
+        &self,
+        filename: &str,
+        content: &[u8],
+    ) -> Result<HarvesterBatch, ParseError> {
+        if let Some(ecosystem_id) = Self::ecosystem_for_filename(filename) {
+            if let Some(parser) = self.by_ecosystem.get(ecosystem_id) {
+                if let Ok(batch) = parser.parse(content).await {
+                    return Ok(batch);
+                }
+            }
+        }
+
+        for ecosystem_id in &self.priority {
+            let parser = &self.by_ecosystem[ecosystem_id];
+            if let Ok(batch) = parser.parse(content).await {
+                return Ok(batch);
+            }
+        }
+
+        Err(ParseError::Unknown(format!(
+            "no registered parser could handle '{filename}'"
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+
+    struct FixedParser {
+        ecosystem_id: &'static str,
+        succeeds: bool,
+    }
+
+    #[async_trait]
+    impl EcosystemParser for FixedParser {
+        fn ecosystem_id(&self) -> &str {
+            self.ecosystem_id
+        }
+
+        async fn parse(&self, _content: &[u8]) -> Result<HarvesterBatch, ParseError> {
+            if self.succeeds {
+                Ok(HarvesterBatch {
+                    nodes: vec![],
+                    vulnerabilities: vec![],
+                    source_vcs: None,
+                })
+            } else {
+                Err(ParseError::InvalidContent("not my format".to_string()))
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_parse_auto_dispatches_by_filename_convention() {
+        let mut registry = ParserRegistry::new();
+        registry.register(Box::new(FixedParser {
+            ecosystem_id: "cargo",
+            succeeds: true,
+        }));
+
+        let result = registry.parse_auto("Cargo.toml", b"[package]").await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_parse_auto_falls_back_to_priority_order() {
+        let mut registry = ParserRegistry::new();
+        registry.register(Box::new(FixedParser {
+            ecosystem_id: "npm",
+            succeeds: false,
+        }));
+        registry.register(Box::new(FixedParser {
+            ecosystem_id: "cargo",
+            succeeds: true,
+        }));
+
+        // "unknown.manifest" matches no filename convention, so every
+        // registered parser is tried in registration order.
+        let result = registry.parse_auto("unknown.manifest", b"anything").await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_parse_auto_falls_back_when_convention_matched_parser_errors() {
+        let mut registry = ParserRegistry::new();
+        registry.register(Box::new(FixedParser {
+            ecosystem_id: "cargo",
+            succeeds: false,
+        }));
+        registry.register(Box::new(FixedParser {
+            ecosystem_id: "npm",
+            succeeds: true,
+        }));
+
+        // "Cargo.toml" conventionally matches the registered "cargo" parser,
+        // but it errors, so parse_auto must fall through to the priority
+        // loop and succeed via "npm" rather than propagating the error.
+        let result = registry
+            .parse_auto("Cargo.toml", b"not cargo after all")
+            .await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_parse_auto_errors_when_nothing_matches() {
+        let registry = ParserRegistry::new();
+        let result = registry.parse_auto("package.json", b"{}").await;
+        assert!(matches!(result, Err(ParseError::Unknown(_))));
+    }
+}